@@ -41,6 +41,26 @@ fn rayon_exp_regular(bench: &mut Bencher)
     });
 }
 
+#[bench]
+fn rayon_add_scalar_regular(bench: &mut Bencher)
+{
+    set_threads();
+    let mut a = Array2::<f64>::zeros((ADDN, ADDN));
+    bench.iter(|| {
+        a.par_map_inplace(|x| *x += 1.);
+    });
+}
+
+#[bench]
+fn rayon_add_scalar_min_len(bench: &mut Bencher)
+{
+    set_threads();
+    let mut a = Array2::<f64>::zeros((ADDN, ADDN));
+    bench.iter(|| {
+        a.par_map_inplace_min_len(4096, |x| *x += 1.);
+    });
+}
+
 const FASTEXP: usize = EXP_N;
 
 #[inline]
@@ -86,3 +86,55 @@ fn test_gen_mat_mul_uses_blas()
         }
     }
 }
+
+#[test]
+fn test_bmm_uses_blas()
+{
+    let a: Array3<f64> = ArrayBuilder::new((3, 10, 8)).build();
+    // batches of c- and f-order matrices
+    for b in [
+        ArrayBuilder::new((3, 8, 9)).build(),
+        ArrayBuilder::new((3, 9, 8))
+            .build()
+            .permuted_axes([0, 2, 1]),
+    ] {
+        let pre_count = CALL_COUNT.with(|ctx| *ctx.borrow());
+        let c = a.bmm(&b);
+        let after_count = CALL_COUNT.with(|ctx| *ctx.borrow());
+        assert_eq!(c.dim(), (3, 10, 9));
+        assert_eq!(after_count - pre_count, 3, "bmm should use one gemm per matrix");
+    }
+
+    // the matrices of an f-order array are not contiguous in either axis
+    let b: Array3<f64> = ArrayBuilder::new((3, 8, 9)).memory_order(Order::F).build();
+    let pre_count = CALL_COUNT.with(|ctx| *ctx.borrow());
+    a.bmm(&b);
+    let after_count = CALL_COUNT.with(|ctx| *ctx.borrow());
+    assert_eq!(after_count - pre_count, 0);
+}
+
+#[test]
+fn test_matmul_uses_blas()
+{
+    let a: Array2<f64> = ArrayBuilder::new((10, 8)).build();
+    let b: Array2<f64> = ArrayBuilder::new((8, 9)).memory_order(Order::F).build();
+
+    let pre_count = CALL_COUNT.with(|ctx| *ctx.borrow());
+    let c = a.matmul(&b);
+    let after_count = CALL_COUNT.with(|ctx| *ctx.borrow());
+    assert_eq!(c.dim(), (10, 9));
+    assert_eq!(after_count - pre_count, 1, "matmul should use gemm");
+}
+
+#[test]
+fn test_outer_uses_blas()
+{
+    let a: Array1<f64> = ArrayBuilder::new(10).build();
+    let b: Array1<f64> = ArrayBuilder::new(12).build();
+
+    let pre_count = CALL_COUNT.with(|ctx| *ctx.borrow());
+    let c = a.outer(&b);
+    let after_count = CALL_COUNT.with(|ctx| *ctx.borrow());
+    assert_eq!(c.dim(), (10, 12));
+    assert_eq!(after_count - pre_count, 1, "outer should use gemm");
+}
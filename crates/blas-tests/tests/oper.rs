@@ -445,3 +445,33 @@ fn vec_mat_mul()
         }
     }
 }
+
+#[test]
+fn ger_rank1_update()
+{
+    let m = 17;
+    let n = 9;
+    let x = range1_mat64(m);
+    let y = range1_mat64(n) + 1.;
+    for &order in &[Order::C, Order::F] {
+        let mut a = Array2::<f64>::ones((m, n).set_f(order == Order::F));
+        let mut expected = a.clone();
+        for i in 0..m {
+            for j in 0..n {
+                expected[[i, j]] += 0.5 * x[i] * y[j];
+            }
+        }
+        a.ger(0.5, &x, &y);
+        assert_relative_eq!(a, expected);
+    }
+
+    let mut c = range_mat_complex(4, 3);
+    let x = Array1::from_iter((0..4).map(|i| Complex32::new(i as f32, 1.)));
+    let y = Array1::from_iter((0..3).map(|i| Complex32::new(1., -(i as f32))));
+    // negative stride in x
+    let x = x.slice(s![..;-1]);
+    let mut expected = c.clone();
+    ndarray::Zip::indexed(&mut expected).for_each(|(i, j), elt| *elt += x[i] * y[j]);
+    c.ger(Complex32::new(1., 0.), &x, &y);
+    assert_eq!(c, expected);
+}
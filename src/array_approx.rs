@@ -193,3 +193,58 @@ macro_rules! impl_approx_traits {
 
 #[cfg(feature = "approx")]
 impl_approx_traits!(approx, "**Requires crate feature `\"approx\"`.**");
+
+/// Assert that two arrays have the same shape and that all their elements are within `tol`
+/// of each other (absolute difference).
+///
+/// Uses [`max_abs_diff`](crate::ArrayBase::max_abs_diff). On failure the panic message names the
+/// index of the largest difference, the two values there and the difference, instead of
+/// printing the whole arrays. A NaN difference always fails.
+///
+/// **Requires crate features `"approx"` and `"std"`.**
+///
+/// ```should_panic
+/// use ndarray::{array, assert_arrays_close};
+///
+/// let a = array![[1., 2.], [3., 4.]];
+/// assert_arrays_close!(a, array![[1., 2.], [3., 4. + 1e-10]], 1e-8);
+///
+/// // panics with "assertion failed: arrays are not close (tolerance 1e-8):
+/// // at index (1, 0), left is 3.0 and right is 3.1, difference 0.10000000000000009"
+/// assert_arrays_close!(a, array![[1., 2.], [3.1, 4.]], 1e-8);
+/// ```
+#[cfg(all(feature = "approx", feature = "std"))]
+#[macro_export]
+macro_rules! assert_arrays_close {
+    ($left:expr, $right:expr, $tol:expr,) => {
+        $crate::assert_arrays_close!($left, $right, $tol)
+    };
+    ($left:expr, $right:expr, $tol:expr) => {
+        match (&$left, &$right, $tol) {
+            (left, right, tol) => {
+                if left.shape() != right.shape() {
+                    panic!(
+                        "assertion failed: arrays are not close: shapes {:?} and {:?} differ",
+                        left.shape(),
+                        right.shape()
+                    );
+                }
+                if let Some((index, diff)) = left.max_abs_diff(right) {
+                    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+                    let fail = !(diff <= tol);
+                    if fail {
+                        panic!(
+                            "assertion failed: arrays are not close (tolerance {:?}): \
+                             at index {:?}, left is {:?} and right is {:?}, difference {:?}",
+                            tol,
+                            index,
+                            left[index.clone()],
+                            right[index.clone()],
+                            diff
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
@@ -10,6 +10,7 @@
 use alloc::boxed::Box;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
+use std::convert::TryFrom;
 use std::hash;
 use std::mem;
 use std::mem::size_of;
@@ -19,6 +20,7 @@ use std::{iter::FromIterator, slice};
 use crate::imp_prelude::*;
 use crate::Arc;
 
+use crate::error::LaneLengthError;
 use crate::{
     dimension,
     iter::{Iter, IterMut},
@@ -195,6 +197,49 @@ where S: DataOwned<Elem = A>
     }
 }
 
+impl<A, S> TryFrom<Vec<Vec<A>>> for ArrayBase<S, Ix2>
+where S: DataOwned<Elem = A>
+{
+    type Error = LaneLengthError;
+
+    /// Create a two-dimensional array from a vector of rows, moving the elements.
+    ///
+    /// An empty vector of rows gives a 0 × 0 array, and empty rows give an
+    /// array with zero columns.
+    ///
+    /// **Errors** if the rows don't all have the same length, reporting the
+    /// index and length of the first row that differs from the first row, or
+    /// if the number of elements would overflow `isize`. The error converts
+    /// into a [`ShapeError`](crate::ShapeError) of kind `IncompatibleShape` or `Overflow`.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array2};
+    /// use std::convert::TryFrom;
+    ///
+    /// let a = Array2::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    /// assert_eq!(a, array![[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// let err = Array2::try_from(vec![vec![1, 2], vec![3, 4], vec![5]]).unwrap_err();
+    /// assert_eq!(err.index(), Some(2));
+    /// assert_eq!(err.found_len(), Some(1));
+    /// assert_eq!(err.expected_len(), 2);
+    /// ```
+    fn try_from(rows: Vec<Vec<A>>) -> Result<Self, LaneLengthError>
+    {
+        let ncols = rows.first().map_or(0, Vec::len);
+        if let Some((i, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != ncols) {
+            return Err(LaneLengthError::mismatch(Axis(0), ncols, i, row.len()));
+        }
+        let dim = Ix2(rows.len(), ncols);
+        let size = dimension::size_of_shape_checked(&dim).map_err(|_| LaneLengthError::overflow(Axis(0), ncols))?;
+        let mut v = Vec::with_capacity(size);
+        for row in rows {
+            v.extend(row);
+        }
+        unsafe { Ok(Self::from_shape_vec_unchecked(dim, v)) }
+    }
+}
+
 impl<A, S> FromIterator<A> for ArrayBase<S, Ix1>
 where S: DataOwned<Elem = A>
 {
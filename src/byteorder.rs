@@ -0,0 +1,189 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Byte order conversions for arrays of primitive numbers.
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use std::convert::TryInto;
+use std::mem::size_of;
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::Shape;
+
+/// Elements that have a byte order: the primitive integer and floating point types.
+///
+/// This trait can not be implemented outside of the crate.
+pub trait ByteSwap: Copy
+{
+    /// Reverse the byte order of the value.
+    fn swap_bytes(self) -> Self;
+
+    /// Create a value from its representation as bytes in big endian order.
+    ///
+    /// **Panics** if the length of `bytes` is not the size of `Self`.
+    fn from_be_slice(bytes: &[u8]) -> Self;
+
+    /// Create a value from its representation as bytes in little endian order.
+    ///
+    /// **Panics** if the length of `bytes` is not the size of `Self`.
+    fn from_le_slice(bytes: &[u8]) -> Self;
+
+    private_decl! {}
+}
+
+macro_rules! impl_byteswap_int {
+    ($($t:ty),*) => {
+        $(
+        impl ByteSwap for $t
+        {
+            #[inline]
+            fn swap_bytes(self) -> Self
+            {
+                <$t>::swap_bytes(self)
+            }
+
+            #[inline]
+            fn from_be_slice(bytes: &[u8]) -> Self
+            {
+                <$t>::from_be_bytes(bytes.try_into().expect("ndarray: wrong number of bytes"))
+            }
+
+            #[inline]
+            fn from_le_slice(bytes: &[u8]) -> Self
+            {
+                <$t>::from_le_bytes(bytes.try_into().expect("ndarray: wrong number of bytes"))
+            }
+
+            private_impl! {}
+        }
+        )*
+    };
+}
+
+macro_rules! impl_byteswap_float {
+    ($($t:ty),*) => {
+        $(
+        impl ByteSwap for $t
+        {
+            #[inline]
+            fn swap_bytes(self) -> Self
+            {
+                <$t>::from_bits(self.to_bits().swap_bytes())
+            }
+
+            #[inline]
+            fn from_be_slice(bytes: &[u8]) -> Self
+            {
+                <$t>::from_be_bytes(bytes.try_into().expect("ndarray: wrong number of bytes"))
+            }
+
+            #[inline]
+            fn from_le_slice(bytes: &[u8]) -> Self
+            {
+                <$t>::from_le_bytes(bytes.try_into().expect("ndarray: wrong number of bytes"))
+            }
+
+            private_impl! {}
+        }
+        )*
+    };
+}
+
+impl_byteswap_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_byteswap_float!(f32, f64);
+
+/// # Byte Order Conversions
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    /// Reverse the byte order of each element in place.
+    ///
+    /// This converts between big and little endian representations, for example
+    /// for data that was loaded from a file written on a machine of the other
+    /// endianness.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![0x1234u16, 0xabcd];
+    /// a.swap_bytes_inplace();
+    /// assert_eq!(a, array![0x3412, 0xcdab]);
+    /// ```
+    pub fn swap_bytes_inplace(&mut self)
+    where
+        S: DataMut,
+        A: ByteSwap,
+    {
+        self.mapv_inplace(A::swap_bytes)
+    }
+
+    /// Create an array with the given shape from raw bytes, in big endian order.
+    ///
+    /// `bytes` holds the elements back to back in the order given by the shape
+    /// (row major unless `.f()` is used); its length must be
+    /// `shape.size() * size_of::<A>()`. The bytes are copied, so they don't need any
+    /// particular alignment.
+    ///
+    /// **Errors** if the length of `bytes` doesn't match the shape (error kind
+    /// `IncompatibleShape`), or if the shape would overflow `isize` (error kind
+    /// `Overflow`).
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let bytes = [0, 1, 0, 2, 0, 3, 0, 4];
+    /// let a = Array2::<u16>::from_be_bytes((2, 2), &bytes).unwrap();
+    /// assert_eq!(a, array![[1, 2], [3, 4]]);
+    /// ```
+    pub fn from_be_bytes<Sh>(shape: Sh, bytes: &[u8]) -> Result<Self, ShapeError>
+    where
+        S: DataOwned,
+        A: ByteSwap,
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        Self::from_bytes_impl(shape.into_shape_with_order(), bytes, A::from_be_slice)
+    }
+
+    /// Create an array with the given shape from raw bytes, in little endian order.
+    ///
+    /// See [`from_be_bytes`](Self::from_be_bytes) for the requirements on `bytes`.
+    ///
+    /// **Errors** if the length of `bytes` doesn't match the shape (error kind
+    /// `IncompatibleShape`), or if the shape would overflow `isize` (error kind
+    /// `Overflow`).
+    ///
+    /// ```
+    /// use ndarray::{array, Array1};
+    ///
+    /// let bytes = 1.5f32.to_le_bytes();
+    /// let a = Array1::<f32>::from_le_bytes(1, &bytes).unwrap();
+    /// assert_eq!(a, array![1.5]);
+    /// ```
+    pub fn from_le_bytes<Sh>(shape: Sh, bytes: &[u8]) -> Result<Self, ShapeError>
+    where
+        S: DataOwned,
+        A: ByteSwap,
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        Self::from_bytes_impl(shape.into_shape_with_order(), bytes, A::from_le_slice)
+    }
+
+    fn from_bytes_impl(shape: Shape<D>, bytes: &[u8], from_slice: fn(&[u8]) -> A) -> Result<Self, ShapeError>
+    where S: DataOwned
+    {
+        let size = crate::dimension::size_of_shape_checked(&shape.dim)?;
+        if size.checked_mul(size_of::<A>()) != Some(bytes.len()) {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        let v: Vec<A> = bytes.chunks_exact(size_of::<A>()).map(from_slice).collect();
+        Self::from_shape_vec(shape, v)
+    }
+}
@@ -85,6 +85,21 @@ impl<A> OwnedRepr<A>
         self.as_nonnull_mut()
     }
 
+    /// Shrink the capacity to the length; return the new pointer
+    ///
+    /// ## Safety
+    ///
+    /// Note that existing pointers into the data are invalidated
+    #[must_use = "must use new pointer to update existing pointers"]
+    pub(crate) fn shrink_to_fit(&mut self) -> NonNull<A>
+    {
+        self.modify_as_vec(|mut v| {
+            v.shrink_to_fit();
+            v
+        });
+        self.as_nonnull_mut()
+    }
+
     /// Set the valid length of the data
     ///
     /// ## Safety
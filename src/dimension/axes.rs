@@ -99,6 +99,8 @@ where D: Dimension
     }
 }
 
+impl<D> ExactSizeIterator for Axes<'_, D> where D: Dimension {}
+
 impl<D> DoubleEndedIterator for Axes<'_, D>
 where D: Dimension
 {
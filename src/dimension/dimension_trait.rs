@@ -390,6 +390,32 @@ pub trait Dimension:
         }
     }
 
+    /// Return the dimension value with a new axis of length 1 inserted at `axis`.
+    ///
+    /// This is shape arithmetic on the dimension value only; it is useful for
+    /// computing the shape of an output array of larger dimensionality. See
+    /// [`RemoveAxis::with_axis_removed`] for the inverse operation.
+    ///
+    /// ***Panics*** if `axis` is greater than the number of axes.
+    ///
+    /// ```
+    /// use ndarray::{Axis, Dim, Dimension};
+    ///
+    /// assert_eq!(Dim([2, 3]).with_axis_inserted(Axis(1)), Dim([2, 1, 3]));
+    /// assert_eq!(Dim([2, 3]).with_axis_inserted(Axis(2)), Dim([2, 3, 1]));
+    /// ```
+    #[track_caller]
+    fn with_axis_inserted(&self, axis: Axis) -> Self::Larger
+    {
+        assert!(
+            axis.index() <= self.ndim(),
+            "ndarray: can not insert axis {} in a dimension of {} axes",
+            axis.index(),
+            self.ndim()
+        );
+        self.insert_axis(axis)
+    }
+
     #[doc(hidden)]
     fn insert_axis(&self, axis: Axis) -> Self::Larger;
 
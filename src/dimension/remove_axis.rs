@@ -14,6 +14,31 @@ use crate::{Axis, Dim, Dimension, Ix, Ix0, Ix1};
 /// removing one axis from *Self* gives smaller dimension *Smaller*.
 pub trait RemoveAxis: Dimension
 {
+    /// Return the dimension value with `axis` removed.
+    ///
+    /// This is shape arithmetic on the dimension value only; it is useful for
+    /// computing the shape of an output array of smaller dimensionality. See
+    /// [`Dimension::with_axis_inserted`] for the inverse operation.
+    ///
+    /// ***Panics*** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{Axis, Dim, RemoveAxis};
+    ///
+    /// assert_eq!(Dim([4, 5, 6]).with_axis_removed(Axis(1)), Dim([4, 6]));
+    /// ```
+    #[track_caller]
+    fn with_axis_removed(&self, axis: Axis) -> Self::Smaller
+    {
+        assert!(
+            axis.index() < self.ndim(),
+            "ndarray: can not remove axis {} from a dimension of {} axes",
+            axis.index(),
+            self.ndim()
+        );
+        self.remove_axis(axis)
+    }
+
     fn remove_axis(&self, axis: Axis) -> Self::Smaller;
 }
 
@@ -539,7 +539,8 @@
 //! `a[:,np.newaxis]` or `np.expand_dims(a, axis=1)` | [`a.slice(s![.., NewAxis])`][.slice()] or [`a.insert_axis(Axis(1))`][.insert_axis()] | create an view of 1-D array `a`, inserting a new axis 1
 //! `a.transpose()` or `a.T` | [`a.t()`][.t()] or [`a.reversed_axes()`][.reversed_axes()] | transpose of array `a` (view for `.t()` or by-move for `.reversed_axes()`)
 //! `np.diag(a)` | [`a.diag()`][.diag()] | view the diagonal of `a`
-//! `a.flatten()` | [`use std::iter::FromIterator; Array::from_iter(a.iter().cloned())`][::from_iter()] | create a 1-D array by flattening `a`
+//! `a.flatten()` | [`a.flatten().into_owned()`][.flatten()] | create a 1-D array by flattening `a`
+//! `np.ravel(a)` | [`a.flatten()`][.flatten()] | flatten `a` to a 1-D array, as a view if possible and a copy otherwise
 //!
 //! ## Iteration
 //!
@@ -654,6 +655,20 @@
 //! convert `f32` array to `i32` array with ["saturating" conversion][sat_conv]; care needed because it can be a lossy conversion or result in non-finite values! See [the reference for information][as_typecast].
 //!
 //! </td></tr>
+//!
+//! <tr><td>
+//!
+//! `a.astype(np.int32)`
+//!
+//! </td><td>
+//!
+//! [`a.cast_elem::<i32>()`][.cast_elem()] or [`a.try_cast_elem::<i32>()`][.try_cast_elem()]
+//!
+//! </td><td>
+//!
+//! shorthands for the conversions above: `cast_elem` converts any primitive numeric type like `as`, and `try_cast_elem` converts losslessly with `TryFrom`, returning an error if any value can not be converted
+//!
+//! </td></tr>
 //! </table>
 //!
 //! [as_conv]: https://doc.rust-lang.org/rust-by-example/types/cast.html
@@ -676,6 +691,8 @@
 //! [.ncols()]: ArrayBase::ncols
 //! [.column()]: ArrayBase::column
 //! [.column_mut()]: ArrayBase::column_mut
+//! [.cast_elem()]: ArrayBase::cast_elem
+//! [.try_cast_elem()]: ArrayBase::try_cast_elem
 //! [concatenate()]: crate::concatenate()
 //! [concatenate!]: crate::concatenate!
 //! [stack!]: crate::stack!
@@ -684,6 +701,7 @@
 //! [.dim()]: ArrayBase::dim
 //! [::eye()]: ArrayBase::eye
 //! [.fill()]: ArrayBase::fill
+//! [.flatten()]: ArrayBase::flatten
 //! [.fold()]: ArrayBase::fold
 //! [.fold_axis()]: ArrayBase::fold_axis
 //! [::from_elem()]: ArrayBase::from_elem
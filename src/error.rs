@@ -6,6 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 use super::Dimension;
+use crate::{Axis, IxDyn};
 #[cfg(feature = "std")]
 use std::error::Error;
 use std::fmt;
@@ -114,3 +115,211 @@ where
 {
     from_kind(ErrorKind::IncompatibleShape)
 }
+
+/// An error from broadcasting an array to a shape that it is not compatible with.
+///
+/// It records both shapes and, when the problem is a single axis, which axis
+/// that is. It converts into a [`ShapeError`], so it can be propagated with `?`
+/// in functions that return one.
+#[derive(Clone, PartialEq)]
+pub struct BroadcastError
+{
+    from: IxDyn,
+    to: IxDyn,
+    reason: BroadcastErrorReason,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum BroadcastErrorReason
+{
+    /// The axis (of the target shape) has an incompatible length
+    Axis(usize),
+    /// The target shape has fewer axes than the array
+    TooFewAxes,
+    /// The target shape overflows `isize`
+    Overflow,
+}
+
+impl BroadcastError
+{
+    pub(crate) fn new<D, E>(from: &D, to: &E, reason: BroadcastErrorReason) -> Self
+    where
+        D: Dimension,
+        E: Dimension,
+    {
+        BroadcastError {
+            from: IxDyn(from.slice()),
+            to: IxDyn(to.slice()),
+            reason,
+        }
+    }
+
+    /// Return the shape of the array that was broadcast.
+    pub fn from_shape(&self) -> &[usize]
+    {
+        self.from.slice()
+    }
+
+    /// Return the shape that the array was broadcast to.
+    pub fn to_shape(&self) -> &[usize]
+    {
+        self.to.slice()
+    }
+
+    /// Return the axis of the target shape that the array can not be broadcast
+    /// along, if the failure is due to a single axis.
+    ///
+    /// This is `None` if the target shape has fewer axes than the array, or if
+    /// its number of elements would overflow `isize`.
+    pub fn axis(&self) -> Option<Axis>
+    {
+        match self.reason {
+            BroadcastErrorReason::Axis(axis) => Some(Axis(axis)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BroadcastError {}
+
+impl fmt::Display for BroadcastError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let (from, to) = (self.from_shape(), self.to_shape());
+        write!(f, "BroadcastError: can not broadcast shape {:?} to {:?}: ", from, to)?;
+        match self.reason {
+            BroadcastErrorReason::Axis(axis) => {
+                let from_len = from[axis + from.len() - to.len()];
+                write!(f, "axis {} has length {}, which is not 1 or {}", axis, from_len, to[axis])
+            }
+            BroadcastErrorReason::TooFewAxes => write!(f, "the target shape has fewer axes"),
+            BroadcastErrorReason::Overflow => write!(f, "the target shape overflows isize"),
+        }
+    }
+}
+
+impl fmt::Debug for BroadcastError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self)
+    }
+}
+
+impl From<BroadcastError> for ShapeError
+{
+    fn from(error: BroadcastError) -> Self
+    {
+        match error.reason {
+            BroadcastErrorReason::Overflow => from_kind(ErrorKind::Overflow),
+            _ => from_kind(ErrorKind::IncompatibleShape),
+        }
+    }
+}
+
+/// An error from creating a two-dimensional array from rows or columns of
+/// different lengths.
+///
+/// It records the first lane (row or column) whose length differs from that of
+/// the first lane, if that is the problem. It converts into a [`ShapeError`], so
+/// it can be propagated with `?` in functions that return one.
+#[derive(Clone, PartialEq)]
+pub struct LaneLengthError
+{
+    /// The axis of the result that the lanes are stacked along: `Axis(0)` for rows
+    /// and `Axis(1)` for columns
+    axis: Axis,
+    expected_len: usize,
+    /// The index and length of the first lane with a different length, or `None` if the
+    /// number of elements overflows `isize`
+    lane: Option<(usize, usize)>,
+}
+
+impl LaneLengthError
+{
+    pub(crate) fn mismatch(axis: Axis, expected_len: usize, index: usize, len: usize) -> Self
+    {
+        LaneLengthError {
+            axis,
+            expected_len,
+            lane: Some((index, len)),
+        }
+    }
+
+    pub(crate) fn overflow(axis: Axis, expected_len: usize) -> Self
+    {
+        LaneLengthError {
+            axis,
+            expected_len,
+            lane: None,
+        }
+    }
+
+    /// Return the axis that the lanes are stacked along in the result: `Axis(0)`
+    /// if they are rows and `Axis(1)` if they are columns.
+    pub fn axis(&self) -> Axis
+    {
+        self.axis
+    }
+
+    /// Return the index of the first lane whose length differs from the length
+    /// of the first lane, or `None` if the lanes have the same length but the
+    /// number of elements would overflow `isize`.
+    pub fn index(&self) -> Option<usize>
+    {
+        self.lane.map(|(index, _)| index)
+    }
+
+    /// Return the length of the lane reported by [`.index()`](Self::index).
+    pub fn found_len(&self) -> Option<usize>
+    {
+        self.lane.map(|(_, len)| len)
+    }
+
+    /// Return the length of the first lane, which is the expected length of
+    /// every lane.
+    pub fn expected_len(&self) -> usize
+    {
+        self.expected_len
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for LaneLengthError {}
+
+impl fmt::Display for LaneLengthError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let lane = if self.axis == Axis(0) { "row" } else { "column" };
+        match self.lane {
+            Some((index, len)) => write!(
+                f,
+                "LaneLengthError: {} {} has length {}, but the first {} has length {}",
+                lane, index, len, lane, self.expected_len
+            ),
+            None => write!(f, "LaneLengthError: the number of elements overflows isize"),
+        }
+    }
+}
+
+impl fmt::Debug for LaneLengthError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self)
+    }
+}
+
+impl From<LaneLengthError> for ShapeError
+{
+    fn from(error: LaneLengthError) -> Self
+    {
+        match error.lane {
+            Some(_) => from_kind(ErrorKind::IncompatibleShape),
+            None => from_kind(ErrorKind::Overflow),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Methods for zero-dimensional arrays.
+use crate::imp_prelude::*;
+
+/// # Methods For 0-D Arrays
+impl<A, S> ArrayBase<S, Ix0>
+where S: RawData<Elem = A>
+{
+    /// Return a reference to the single element of the zero-dimensional array.
+    ///
+    /// This is the same as `&self[()]`, and works for any zero-dimensional array,
+    /// owned or view. It is useful for unwrapping reductions that produce a
+    /// zero-dimensional result.
+    ///
+    /// See also `.into_scalar()` on [`Array0`](Array::into_scalar) and
+    /// [`ArrayView0`](ArrayView::into_scalar), which consume the array.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![1, 2, 3];
+    /// let sum = a.sum_axis(Axis(0));
+    /// assert_eq!(sum.as_scalar(), &6);
+    /// assert_eq!(a.slice(ndarray::s![1]).as_scalar(), &2);
+    /// ```
+    pub fn as_scalar(&self) -> &A
+    where S: Data
+    {
+        &self[()]
+    }
+}
@@ -11,8 +11,22 @@
 use alloc::vec::Vec;
 use std::mem::MaybeUninit;
 
+use crate::error::{self, ShapeError};
 use crate::imp_prelude::*;
 use crate::low_level_util::AbortIfPanic;
+use num_traits::Zero;
+
+/// Which end of an array to trim, see [`trim_zeros`](ArrayBase::trim_zeros).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TrimMode
+{
+    /// Trim leading elements only.
+    Front,
+    /// Trim trailing elements only.
+    Back,
+    /// Trim both leading and trailing elements.
+    Both,
+}
 
 /// # Methods For 1-D Arrays
 impl<A, S> ArrayBase<S, Ix1>
@@ -31,6 +45,124 @@ where S: RawData<Elem = A>
         }
     }
 
+    /// Return `true` if the array is sorted in non-decreasing order.
+    ///
+    /// This is [`.is_sorted_axis(Axis(0))`](ArrayBase::is_sorted_axis); see there
+    /// for elements that are only partially ordered, like floats with NaN.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert!(array![1, 2, 2, 9].is_sorted());
+    /// assert!(!array![1, 3, 2].is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool
+    where
+        A: PartialOrd,
+        S: Data,
+    {
+        self.is_sorted_axis(Axis(0))
+    }
+
+    /// Reshape the array into rows of `group_size` consecutive elements.
+    ///
+    /// An array of length *n* becomes an array of shape (*n* / `group_size`,
+    /// `group_size`), where row *i* holds elements `i * group_size..(i + 1) * group_size`.
+    /// This only adjusts the array's dimensions and strides, so it works for any
+    /// 1-D array and never copies the data.
+    ///
+    /// **Errors** if `group_size` is zero or *n* is not divisible by it (error kind
+    /// `IncompatibleShape`).
+    ///
+    /// ```
+    /// use ndarray::{array, ErrorKind};
+    ///
+    /// let points = array![0., 0., 1., 1., 2., 4.];
+    /// let xy = points.group_1d(2).unwrap();
+    /// assert_eq!(xy, array![[0., 0.], [1., 1.], [2., 4.]]);
+    ///
+    /// let xyz = array![1, 2, 3, 4].group_1d(3);
+    /// assert_eq!(xyz.unwrap_err().kind(), ErrorKind::IncompatibleShape);
+    /// ```
+    pub fn group_1d(self, group_size: usize) -> Result<ArrayBase<S, Ix2>, ShapeError>
+    {
+        let n = self.len();
+        if group_size == 0 || n % group_size != 0 {
+            return Err(error::incompatible_shapes(&self.dim, &Ix2(n / group_size.max(1), group_size)));
+        }
+        let dim = Ix2(n / group_size, group_size);
+        let strides = if n == 0 {
+            dim.default_strides()
+        } else {
+            let s = self.strides[0] as isize;
+            // the row stride only matters if there are several rows, and then it is within
+            // the extent of the array, so it can't overflow
+            Ix2(s.wrapping_mul(group_size as isize) as usize, s as usize)
+        };
+        // safe because the new array covers exactly the same elements, in the same order
+        unsafe { Ok(self.with_strides_dim(strides, dim)) }
+    }
+
+    /// Return a view of the array without its leading and/or trailing zeros,
+    /// like `numpy.trim_zeros`.
+    ///
+    /// This is a slicing operation: the result is a view of `self` and no
+    /// elements are copied. If all elements are zero, the result is empty.
+    ///
+    /// ```
+    /// use ndarray::{array, TrimMode};
+    ///
+    /// let signal = array![0, 0, 1, 2, 0, 3, 0];
+    /// assert_eq!(signal.trim_zeros(TrimMode::Both), array![1, 2, 0, 3]);
+    /// assert_eq!(signal.trim_zeros(TrimMode::Front), array![1, 2, 0, 3, 0]);
+    /// assert_eq!(signal.trim_zeros(TrimMode::Back), array![0, 0, 1, 2, 0, 3]);
+    /// assert!(array![0., 0.].trim_zeros(TrimMode::Both).is_empty());
+    /// ```
+    pub fn trim_zeros(&self, mode: TrimMode) -> ArrayView1<'_, A>
+    where
+        A: Zero,
+        S: Data,
+    {
+        let mut start = 0;
+        let mut end = self.len();
+        if mode != TrimMode::Back {
+            start = self.iter().position(|x| !x.is_zero()).unwrap_or(end);
+        }
+        if mode != TrimMode::Front {
+            end -= self
+                .iter()
+                .skip(start)
+                .rev()
+                .position(|x| !x.is_zero())
+                .unwrap_or(end - start);
+        }
+        self.slice(s![start..end])
+    }
+
+    /// Return the indices of the nonzero elements of the array, in increasing order.
+    ///
+    /// This is the one-dimensional case of [`.nonzero()`](ArrayBase::nonzero), as a flat
+    /// vector of indices.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![0., 1.5, 0., -2.];
+    /// assert_eq!(a.nonzero_1d(), array![1, 3]);
+    /// assert_eq!(a.select(ndarray::Axis(0), a.nonzero_1d().as_slice().unwrap()), array![1.5, -2.]);
+    /// ```
+    pub fn nonzero_1d(&self) -> Array1<usize>
+    where
+        A: Zero,
+        S: Data,
+    {
+        self.iter()
+            .enumerate()
+            .filter(|(_, elt)| !elt.is_zero())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
     /// Rotate the elements of the array by 1 element towards the front;
     /// the former first element becomes the last.
     pub(crate) fn rotate1_front(&mut self)
@@ -7,7 +7,11 @@
 // except according to those terms.
 
 //! Methods for two-dimensional arrays.
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 use crate::imp_prelude::*;
+use crate::iter::AxisIter;
 
 /// # Methods For 2-D Arrays
 impl<A, S> ArrayBase<S, Ix2>
@@ -68,6 +72,27 @@ where S: RawData<Elem = A>
         self.len_of(Axis(0))
     }
 
+    /// Return an iterator over the rows of the two-dimensional array.
+    ///
+    /// This is a convenience for [`.axis_iter(Axis(0))`](ArrayBase::axis_iter); the
+    /// iterator yields one-dimensional array views and is double ended and exact size.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let array = array![[1., 2.],
+    ///                    [3., 4.]];
+    /// for (i, row) in array.row_iter().enumerate() {
+    ///     assert_eq!(row, array.row(i));
+    /// }
+    /// assert_eq!(array.row_iter().rev().next().unwrap(), array![3., 4.]);
+    /// ```
+    pub fn row_iter(&self) -> AxisIter<'_, A, Ix1>
+    where S: Data
+    {
+        self.axis_iter(Axis(0))
+    }
+
     /// Return an array view of column `index`.
     ///
     /// **Panics** if `index` is out of bounds.
@@ -123,6 +148,118 @@ where S: RawData<Elem = A>
         self.len_of(Axis(1))
     }
 
+    /// Return an iterator over the columns of the two-dimensional array.
+    ///
+    /// This is a convenience for [`.axis_iter(Axis(1))`](ArrayBase::axis_iter); the
+    /// iterator yields one-dimensional array views and is double ended and exact size.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let array = array![[1., 2.],
+    ///                    [3., 4.]];
+    /// for (j, column) in array.col_iter().enumerate() {
+    ///     assert_eq!(column, array.column(j));
+    /// }
+    /// assert_eq!(array.col_iter().len(), 2);
+    /// ```
+    pub fn col_iter(&self) -> AxisIter<'_, A, Ix1>
+    where S: Data
+    {
+        self.axis_iter(Axis(1))
+    }
+
+    /// Return the elements of the array stacked column by column, as in the `vec`
+    /// operator of linear algebra.
+    ///
+    /// **Note:** the order is **column major** — all of column 0, then all of
+    /// column 1, and so on — no matter the memory layout of the array. This is
+    /// the convention of `vec(·)` in identities like vec(*AXB*) = (*B*ᵀ ⊗ *A*) vec(*X*),
+    /// and it differs from the row major order of `.iter()`, `.flatten()` and friends.
+    ///
+    /// The inverse is [`Array2::unvec`](ArrayBase::unvec).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let x = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(x.vec_column_major(), array![1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn vec_column_major(&self) -> Array1<A>
+    where
+        S: Data,
+        A: Clone,
+    {
+        self.t().iter().cloned().collect()
+    }
+
+    /// Return the rows of the array as a vector of vectors, cloning the
+    /// elements.
+    ///
+    /// This is meant for passing the array to other code that requires nested
+    /// vectors, like some serializers or plotting libraries. It allocates one
+    /// vector per row, which is the overhead ndarray's contiguous arrays are
+    /// there to avoid, so prefer working with the array itself when possible.
+    ///
+    /// The inverse is the `TryFrom<Vec<Vec<A>>>` implementation of `Array2`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.to_nested_vec2(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// assert_eq!(a.t().to_nested_vec2(), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    /// ```
+    pub fn to_nested_vec2(&self) -> Vec<Vec<A>>
+    where
+        S: Data,
+        A: Clone,
+    {
+        self.outer_iter().map(|row| row.to_vec()).collect()
+    }
+
+    /// Reverse the order of the columns, consuming the array and returning it, like
+    /// `numpy.fliplr`.
+    ///
+    /// This is [`.flipped_axis(Axis(1))`](ArrayBase::flipped_axis): it does not move or copy any
+    /// data, it just negates the stride of the columns, so on a view (like `a.view().flip_lr()`)
+    /// it returns a view, not a copy.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let image = array![[1, 2, 3], [4, 5, 6]];
+    /// let mirrored = image.view().flip_lr();
+    /// assert_eq!(mirrored, array![[3, 2, 1], [6, 5, 4]]);
+    /// assert_eq!(mirrored.strides(), &[3, -1]);
+    /// ```
+    pub fn flip_lr(self) -> Self
+    {
+        self.flipped_axis(Axis(1))
+    }
+
+    /// Reverse the order of the rows, consuming the array and returning it, like
+    /// `numpy.flipud`.
+    ///
+    /// This is [`.flipped_axis(Axis(0))`](ArrayBase::flipped_axis): it does not move or copy any
+    /// data, it just negates the stride of the rows, so on a view (like `a.view().flip_ud()`)
+    /// it returns a view, not a copy.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let image = array![[1, 2, 3], [4, 5, 6]];
+    /// let upside_down = image.view().flip_ud();
+    /// assert_eq!(upside_down, array![[4, 5, 6], [1, 2, 3]]);
+    /// assert_eq!(upside_down.strides(), &[-3, 1]);
+    /// ```
+    pub fn flip_ud(self) -> Self
+    {
+        self.flipped_axis(Axis(0))
+    }
+
     /// Return true if the array is square, false otherwise.
     ///
     /// # Examples
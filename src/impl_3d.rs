@@ -0,0 +1,43 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Methods for three-dimensional arrays.
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+
+/// # Methods For 3-D Arrays
+impl<A, S> ArrayBase<S, Ix3>
+where S: RawData<Elem = A>
+{
+    /// Return the array as a vector of vectors of rows, cloning the elements.
+    ///
+    /// Element `[i][j][k]` of the result is `self[[i, j, k]]`, so each
+    /// innermost vector is a lane of the last axis, in logical order.
+    ///
+    /// This is meant for passing the array to other code that requires nested
+    /// vectors. It allocates one vector per lane and one per subview along
+    /// the first axis, see [`.to_nested_vec2()`](ArrayBase::to_nested_vec2).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[[1, 2], [3, 4]],
+    ///                [[5, 6], [7, 8]]];
+    /// assert_eq!(a.to_nested_vec3(),
+    ///            vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5, 6], vec![7, 8]]]);
+    /// ```
+    pub fn to_nested_vec3(&self) -> Vec<Vec<Vec<A>>>
+    where
+        S: Data,
+        A: Clone,
+    {
+        self.outer_iter().map(|mat| mat.to_nested_vec2()).collect()
+    }
+}
@@ -27,4 +27,35 @@ where D: Dimension
         // Only strong pointers are used in this crate.
         Arc::strong_count(&self.data.0) == 1
     }
+
+    /// Make the array data uniquely held, cloning it if it is shared, and
+    /// return a mutable view of it.
+    ///
+    /// This is analogous to `Arc::make_mut`: if the inner `Arc` is not shared,
+    /// no data is cloned. Otherwise the elements are cloned into a new
+    /// allocation that is owned only by `self`, so that other handles to
+    /// the original data are unaffected.
+    ///
+    /// Mutating through the returned view does not need any further
+    /// reference count checks.
+    ///
+    /// ```
+    /// use ndarray::{array, ArcArray};
+    ///
+    /// let mut a: ArcArray<_, _> = array![[1., 2.], [3., 4.]].into_shared();
+    /// let b = a.clone();
+    ///
+    /// let mut view = a.make_mut();
+    /// view[[0, 0]] = 0.;
+    /// view[[1, 1]] = 0.;
+    ///
+    /// assert!(a.is_unique());
+    /// assert_eq!(a, array![[0., 2.], [3., 0.]]);
+    /// assert_eq!(b, array![[1., 2.], [3., 4.]]);
+    /// ```
+    pub fn make_mut(&mut self) -> ArrayViewMut<'_, A, D>
+    where A: Clone
+    {
+        self.view_mut()
+    }
 }
@@ -11,26 +11,30 @@
 //!
 
 #![allow(clippy::match_wild_err_arm)]
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
 use alloc::vec;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use num_traits::Float;
 use num_traits::{One, Zero};
+use std::cmp::Ordering;
 use std::mem;
 use std::mem::MaybeUninit;
 
 use crate::dimension::offset_from_low_addr_ptr_to_logical_ptr;
 use crate::dimension::{self, CanIndexCheckMode};
-use crate::error::{self, ShapeError};
+use crate::error::{self, LaneLengthError, ShapeError};
 use crate::extension::nonnull::nonnull_from_vec_data;
 use crate::imp_prelude::*;
 use crate::indexes;
 use crate::indices;
 #[cfg(feature = "std")]
 use crate::iterators::to_vec;
-use crate::iterators::to_vec_mapped;
 use crate::iterators::TrustedIterator;
+use crate::iterators::{to_vec_mapped, try_to_vec_mapped};
+use crate::IntoDimension;
 use crate::StrideShape;
 #[cfg(feature = "std")]
 use crate::{geomspace, linspace, logspace};
@@ -250,6 +254,161 @@ where S: DataOwned<Elem = A>
         }
         eye
     }
+
+    /// Create a square 2D matrix of size `n` from its bands (diagonals), with zeros
+    /// elsewhere.
+    ///
+    /// Each `(offset, values)` in `bands` places `values` on the `offset`-th diagonal:
+    /// offset `0` is the main diagonal, positive offsets are above it and negative
+    /// offsets are below it. If an offset appears more than once, the last band wins.
+    ///
+    /// **Panics** if the length of `values` is not the length of the `offset`-th
+    /// diagonal, `n - |offset|` (or zero if `|offset| >= n`), or if `n * n` would
+    /// overflow `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array2};
+    ///
+    /// // the tridiagonal second difference operator
+    /// let a = Array2::from_bands(4, &[
+    ///     (-1, array![1., 1., 1.].view()),
+    ///     (0, array![-2., -2., -2., -2.].view()),
+    ///     (1, array![1., 1., 1.].view()),
+    /// ]);
+    /// assert_eq!(a, array![[-2., 1., 0., 0.],
+    ///                      [1., -2., 1., 0.],
+    ///                      [0., 1., -2., 1.],
+    ///                      [0., 0., 1., -2.]]);
+    /// ```
+    #[track_caller]
+    pub fn from_bands(n: usize, bands: &[(isize, ArrayView1<'_, A>)]) -> Self
+    where
+        S: DataMut,
+        A: Clone + Zero,
+    {
+        let mut arr = Self::zeros((n, n));
+        for (offset, values) in bands {
+            let k = offset.unsigned_abs();
+            let diag_len = n.saturating_sub(k);
+            assert_eq!(
+                values.len(),
+                diag_len,
+                "ndarray: band at offset {} must have length {}, but has length {}",
+                offset,
+                diag_len,
+                values.len()
+            );
+            let (row0, col0) = if *offset >= 0 { (0, k) } else { (k, 0) };
+            for (i, value) in values.iter().enumerate() {
+                arr[[row0 + i, col0 + i]] = value.clone();
+            }
+        }
+        arr
+    }
+
+    /// Create a 2D matrix by copying the given rows, which must all have the same length.
+    ///
+    /// The result is in standard layout and has shape `(rows.len(), n)`, where `n`
+    /// is the length of the rows; an empty slice of rows gives a 0 × 0 matrix.
+    ///
+    /// **Errors** if the rows don't all have the same length, reporting the index
+    /// and length of the first row that differs from the first row, or if the
+    /// number of elements would overflow `isize`. The error converts into a
+    /// [`ShapeError`] of kind `IncompatibleShape` or `Overflow`.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array1, Array2};
+    ///
+    /// let rows: Vec<Array1<i32>> = vec![array![1, 2, 3], array![4, 5, 6]];
+    /// let views: Vec<_> = rows.iter().map(|row| row.view()).collect();
+    /// let a = Array2::from_rows(&views).unwrap();
+    /// assert_eq!(a, array![[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// let err = Array2::from_rows(&[array![1, 2].view(), array![3].view()]).unwrap_err();
+    /// assert_eq!(err.index(), Some(1));
+    /// assert_eq!(err.found_len(), Some(1));
+    /// ```
+    pub fn from_rows(rows: &[ArrayView1<'_, A>]) -> Result<Self, LaneLengthError>
+    where A: Clone
+    {
+        let n = lanes_common_len(rows, Axis(0))?;
+        let shape = Ix2(rows.len(), n);
+        let size = dimension::size_of_shape_checked(&shape).map_err(|_| LaneLengthError::overflow(Axis(0), n))?;
+        let mut v = Vec::with_capacity(size);
+        for row in rows {
+            v.extend(row.iter().cloned());
+        }
+        unsafe { Ok(Self::from_shape_vec_unchecked(shape, v)) }
+    }
+
+    /// Create a 2D matrix by copying the given columns, which must all have the same length.
+    ///
+    /// The result is in standard layout and has shape `(n, columns.len())`, where `n`
+    /// is the length of the columns; an empty slice of columns gives a 0 × 0 matrix.
+    ///
+    /// **Errors** if the columns don't all have the same length, reporting the index
+    /// and length of the first column that differs from the first column, or if the
+    /// number of elements would overflow `isize`. The error converts into a
+    /// [`ShapeError`] of kind `IncompatibleShape` or `Overflow`.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array2};
+    ///
+    /// let a = Array2::from_columns(&[array![1, 2].view(), array![3, 4].view()]).unwrap();
+    /// assert_eq!(a, array![[1, 3], [2, 4]]);
+    /// ```
+    pub fn from_columns(columns: &[ArrayView1<'_, A>]) -> Result<Self, LaneLengthError>
+    where A: Clone
+    {
+        let n = lanes_common_len(columns, Axis(1))?;
+        let shape = Ix2(n, columns.len());
+        dimension::size_of_shape_checked(&shape).map_err(|_| LaneLengthError::overflow(Axis(1), n))?;
+        Ok(Self::from_shape_fn(shape, |(i, j)| columns[j][i].clone()))
+    }
+
+    /// Create an *m* × *n* matrix from the stacked columns `v`, the inverse of
+    /// [`vec_column_major`](ArrayBase::vec_column_major).
+    ///
+    /// **Note:** `v` is read in **column major** order: its first *m* elements
+    /// become column 0, the next *m* become column 1, and so on. This is unlike
+    /// [`from_shape_vec`](ArrayBase::from_shape_vec) with a plain shape, which fills
+    /// the matrix row by row. The result is in column major (Fortran) memory layout.
+    ///
+    /// **Errors** if `v.len()` is not `m * n` (error kind `IncompatibleShape`), or
+    /// if the number of elements would overflow `isize` (error kind `Overflow`).
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array2};
+    ///
+    /// let x = Array2::unvec(&array![1, 4, 2, 5, 3, 6], (2, 3)).unwrap();
+    /// assert_eq!(x, array![[1, 2, 3],
+    ///                      [4, 5, 6]]);
+    /// assert_eq!(x.vec_column_major(), array![1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn unvec<S2>(v: &ArrayBase<S2, Ix1>, shape: (usize, usize)) -> Result<Self, ShapeError>
+    where
+        A: Clone,
+        S2: Data<Elem = A>,
+    {
+        let (m, n) = shape;
+        let size = dimension::size_of_shape_checked(&Ix2(m, n))?;
+        if size != v.len() {
+            return Err(error::incompatible_shapes(&Ix1(v.len()), &Ix2(m, n)));
+        }
+        unsafe { Ok(Self::from_shape_vec_unchecked((m, n).f(), v.to_vec())) }
+    }
+}
+
+/// Return the common length of `lanes` (or zero if there are none), or an error
+/// reporting the first lane whose length differs; `axis` is the axis the lanes are
+/// stacked along in the result.
+fn lanes_common_len<A>(lanes: &[ArrayView1<'_, A>], axis: Axis) -> Result<usize, LaneLengthError>
+{
+    let n = lanes.first().map_or(0, |lane| lane.len());
+    if let Some((i, lane)) = lanes.iter().enumerate().find(|(_, lane)| lane.len() != n) {
+        return Err(LaneLengthError::mismatch(axis, n, i, lane.len()));
+    }
+    Ok(n)
 }
 
 #[cfg(not(debug_assertions))]
@@ -422,6 +581,106 @@ where
         }
     }
 
+    /// Create an array with values created by the fallible function `f`.
+    ///
+    /// `f` is called with the index of the element to create; the elements are
+    /// visited in arbitrary order. If `f` returns an error, no more elements are
+    /// created, the elements created so far are dropped, and the error is returned.
+    ///
+    /// This is the fallible version of [`from_shape_fn`](ArrayBase::from_shape_fn).
+    ///
+    /// **Panics** if the product of non-zero axis lengths overflows `isize`.
+    ///
+    /// ```
+    /// use ndarray::{Array, arr2};
+    ///
+    /// let words = [["1", "2"], ["3", "4"]];
+    /// let a = Array::try_from_shape_fn((2, 2), |(i, j)| words[i][j].parse::<i32>());
+    /// assert_eq!(a, Ok(arr2(&[[1, 2], [3, 4]])));
+    ///
+    /// let words = [["1", "2"], ["x", "4"]];
+    /// let b = Array::try_from_shape_fn((2, 2), |(i, j)| words[i][j].parse::<i32>());
+    /// assert!(b.is_err());
+    /// ```
+    pub fn try_from_shape_fn<Sh, F, E>(shape: Sh, f: F) -> Result<Self, E>
+    where
+        Sh: ShapeBuilder<Dim = D>,
+        F: FnMut(D::Pattern) -> Result<A, E>,
+    {
+        let shape = shape.into_shape_with_order();
+        let _ = size_of_shape_checked_unwrap!(&shape.dim);
+        let v = if shape.is_c() {
+            try_to_vec_mapped(indices(shape.dim.clone()).into_iter(), f)?
+        } else {
+            let dim = shape.dim.clone();
+            try_to_vec_mapped(indexes::indices_iter_f(dim), f)?
+        };
+        unsafe { Ok(Self::from_shape_vec_unchecked(shape, v)) }
+    }
+
+    /// Create an array with the given shape by creating each lane along
+    /// `axis` with the function `f`.
+    ///
+    /// `f` is called with the index of the lane, which is the index of the
+    /// element with `axis` removed, and must return the elements of the lane.
+    /// The lanes are visited in logical order.
+    ///
+    /// The elements of each lane end up next to each other in memory, so the
+    /// result is in c-order only if `axis` is the last axis.
+    ///
+    /// **Panics** if `axis` is out of bounds, if a lane returned by `f` does
+    /// not have length `shape[axis]`, or if the product of non-zero axis
+    /// lengths overflows `isize`.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2, Axis};
+    ///
+    /// // Each column is a geometric series with its own ratio
+    /// let a = Array2::from_shape_lanes((3, 2), Axis(0), |j| {
+    ///     let ratio = (j + 2) as f64;
+    ///     array![1., ratio, ratio * ratio]
+    /// });
+    /// assert_eq!(a, array![[1., 1.], [2., 3.], [4., 9.]]);
+    /// ```
+    #[track_caller]
+    pub fn from_shape_lanes<Sh, F>(shape: Sh, axis: Axis, mut f: F) -> Self
+    where
+        Sh: IntoDimension<Dim = D>,
+        D: RemoveAxis,
+        F: FnMut(<D::Smaller as Dimension>::Pattern) -> Array1<A>,
+    {
+        let dim = shape.into_dimension();
+        let _ = size_of_shape_checked_unwrap!(&dim);
+        let n = dim[axis.index()];
+        let ndim = dim.ndim();
+
+        // Collect the lanes in c-order of the shape with `axis` moved last, then move it back
+        let mut v = Vec::with_capacity(dim.size());
+        for index in indices(dim.remove_axis(axis)) {
+            let lane = f(index.clone());
+            ndassert!(
+                lane.len() == n,
+                "ndarray: from_shape_lanes: the lane at index {:?} has length {}, but the axis length is {}",
+                index,
+                lane.len(),
+                n
+            );
+            v.extend(lane);
+        }
+        let mut lanes_last_dim = D::zeros(ndim);
+        let mut axes = D::zeros(ndim);
+        for ax in 0..ndim {
+            // new axis `ax` is axis `axes[ax]` of the lanes-last array
+            axes[ax] = match ax.cmp(&axis.index()) {
+                Ordering::Less => ax,
+                Ordering::Equal => ndim - 1,
+                Ordering::Greater => ax - 1,
+            };
+            lanes_last_dim[axes[ax]] = dim[ax];
+        }
+        unsafe { Self::from_shape_vec_unchecked(lanes_last_dim, v).permuted_axes(axes) }
+    }
+
     /// Create an array with the given shape from a vector. (No cloning of
     /// elements needed.)
     ///
@@ -462,6 +721,28 @@ where
         Self::from_shape_vec_impl(shape.into(), v)
     }
 
+    /// Create an array with the given shape from a boxed slice. (No cloning of
+    /// elements needed.)
+    ///
+    /// The boxed slice is converted to a vector without reallocating, and the
+    /// shape is validated exactly like in [`from_shape_vec`](ArrayBase::from_shape_vec).
+    ///
+    /// **Errors** if `shape` does not correspond to the number of elements in
+    /// `b`, or for the other reasons listed for `from_shape_vec`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array};
+    ///
+    /// let b: Box<[i32]> = vec![1, 2, 3, 4].into_boxed_slice();
+    /// let a = Array::from_boxed_slice((2, 2), b).unwrap();
+    /// assert_eq!(a, arr2(&[[1, 2], [3, 4]]));
+    /// ```
+    pub fn from_boxed_slice<Sh>(shape: Sh, b: Box<[A]>) -> Result<Self, ShapeError>
+    where Sh: Into<StrideShape<D>>
+    {
+        Self::from_shape_vec_impl(shape.into(), b.into_vec())
+    }
+
     fn from_shape_vec_impl(shape: StrideShape<D>, v: Vec<A>) -> Result<Self, ShapeError>
     {
         let dim = shape.dim;
@@ -535,6 +816,27 @@ where
         Self::from_vec_dim_stride_unchecked(dim, strides, v)
     }
 
+    /// Like `from_shape_trusted_iter_unchecked`, but with a fallible `map`; the first
+    /// error is returned.
+    ///
+    /// # Safety
+    ///
+    /// See from_shape_vec_unchecked
+    pub(crate) unsafe fn try_from_shape_trusted_iter_unchecked<Sh, I, F, E>(
+        shape: Sh, iter: I, map: F,
+    ) -> Result<Self, E>
+    where
+        Sh: Into<StrideShape<D>>,
+        I: TrustedIterator + ExactSizeIterator,
+        F: FnMut(I::Item) -> Result<A, E>,
+    {
+        let shape = shape.into();
+        let dim = shape.dim;
+        let strides = shape.strides.strides_for_dim(&dim);
+        let v = try_to_vec_mapped(iter, map)?;
+        Ok(Self::from_vec_dim_stride_unchecked(dim, strides, v))
+    }
+
     /// Create an array with uninitialized elements, shape `shape`.
     ///
     /// The uninitialized elements of type `A` are represented by the type `MaybeUninit<A>`,
@@ -10,9 +10,12 @@ use alloc::slice;
 use alloc::vec;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
+use num_traits::AsPrimitive;
 #[allow(unused_imports)]
 use rawpointer::PointerExt;
-use std::mem::{size_of, ManuallyDrop};
+use std::convert::TryFrom;
+use std::iter::Enumerate;
+use std::mem::{size_of, ManuallyDrop, MaybeUninit};
 
 use crate::imp_prelude::*;
 
@@ -32,7 +35,7 @@ use crate::dimension::{
     stride_offset,
     Axes,
 };
-use crate::error::{self, from_kind, ErrorKind, ShapeError};
+use crate::error::{self, from_kind, BroadcastError, BroadcastErrorReason, ErrorKind, ShapeError};
 use crate::itertools::zip;
 use crate::math_cell::MathCell;
 use crate::order::Order;
@@ -47,6 +50,7 @@ use crate::iter::{
     AxisIter,
     AxisIterMut,
     AxisWindows,
+    AxisWindowsOwned,
     ExactChunks,
     ExactChunksMut,
     IndexedIter,
@@ -154,6 +158,21 @@ where
     }
 
     /// Return the strides of the array as a slice.
+    ///
+    /// The strides are signed and counted in elements (not bytes); an axis which
+    /// is traversed backwards in memory, for example after reversing it, has a
+    /// negative stride.
+    ///
+    /// ```rust
+    /// use ndarray::{array, s};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.strides(), &[3, 1]);
+    ///
+    /// let v = a.slice(s![..;-1, ..;2]);
+    /// assert_eq!(v, array![[4, 6], [1, 3]]);
+    /// assert_eq!(v.strides(), &[-3, 2]);
+    /// ```
     pub fn strides(&self) -> &[isize]
     {
         let s = self.strides.slice();
@@ -166,7 +185,18 @@ where
     /// The axis should be in the range `Axis(` 0 .. *n* `)` where *n* is the
     /// number of dimensions (axes) of the array.
     ///
+    /// Like [`.strides()`](Self::strides), the stride is signed and counted in elements.
+    ///
     /// ***Panics*** if the axis is out of bounds.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[1, 2, 3], [4, 5, 6]];
+    /// a.invert_axis(Axis(1));
+    /// assert_eq!(a.stride_of(Axis(0)), 3);
+    /// assert_eq!(a.stride_of(Axis(1)), -1);
+    /// ```
     #[track_caller]
     pub fn stride_of(&self, axis: Axis) -> isize
     {
@@ -238,15 +268,59 @@ where
         A: Clone,
         S: Data,
     {
-        if let Some(slc) = self.as_slice_memory_order() {
+        // The strides of an empty array can reach outside of an empty buffer, so they are only
+        // reused if there are elements (the same in .map() and .try_map())
+        if let Some(slc) = self.as_slice_memory_order().filter(|slc| !slc.is_empty()) {
             unsafe { Array::from_shape_vec_unchecked(self.dim.clone().strides(self.strides.clone()), slc.to_vec()) }
         } else {
             self.map(A::clone)
         }
     }
 
+    /// Return an uniquely owned copy of the array in column-major (Fortran) memory layout.
+    ///
+    /// Unlike [`.to_owned()`](Self::to_owned), the layout of the result does not depend on the
+    /// layout of `self`: it is always f-contiguous, which is what column-major libraries such as
+    /// LAPACK expect.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// let f = a.to_owned_f();
+    /// assert_eq!(f, a);
+    /// assert_eq!(f.as_slice_memory_order(), Some(&[1, 4, 2, 5, 3, 6][..]));
+    /// ```
+    pub fn to_owned_f(&self) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+    {
+        let t = self.t();
+        if let Some(slc) = t.as_slice() {
+            unsafe { Array::from_shape_vec_unchecked(self.dim.clone().f(), slc.to_vec()) }
+        } else {
+            unsafe { Array::from_shape_trusted_iter_unchecked(self.dim.clone().f(), t.into_iter(), A::clone) }
+        }
+    }
+
     /// Return a shared ownership (copy on write) array, cloning the array
     /// elements if necessary.
+    ///
+    /// For an `ArcArray` this only increments the reference count. For any
+    /// other kind of array, including views, it always allocates a new
+    /// buffer and clones the elements into it; this is a more direct form of
+    /// `.to_owned().into_shared()`.
+    ///
+    /// ```
+    /// use ndarray::{array, s};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// let shared = a.slice(s![.., 1..]).to_shared();
+    /// let handle = shared.clone(); // cheap, e.g. for sending to another thread
+    ///
+    /// assert_eq!(handle, array![[2, 3], [5, 6]]);
+    /// ```
     pub fn to_shared(&self) -> ArcArray<A, D>
     where
         A: Clone,
@@ -309,6 +383,9 @@ where
     /// Returns a reference to the first element of the array, or `None` if it
     /// is empty.
     ///
+    /// The first element is the one at index `[0, 0, ..., 0]`, the first in the
+    /// *logical order* of the array, regardless of its memory layout.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -334,6 +411,8 @@ where
     /// Returns a mutable reference to the first element of the array, or
     /// `None` if it is empty.
     ///
+    /// See [`.first()`](Self::first) for which element this is.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -359,6 +438,10 @@ where
     /// Returns a reference to the last element of the array, or `None` if it
     /// is empty.
     ///
+    /// The last element is the one at the highest index along every axis, the
+    /// last in the *logical order* of the array, regardless of its memory
+    /// layout.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -388,6 +471,8 @@ where
     /// Returns a mutable reference to the last element of the array, or `None`
     /// if it is empty.
     ///
+    /// See [`.last()`](Self::last) for which element this is.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -1089,6 +1174,134 @@ where
         }
     }
 
+    /// Return a copy of the array where each run of consecutive equal subviews
+    /// along `axis` is reduced to its first subview.
+    ///
+    /// This is like [`Vec::dedup`], with the subviews along `axis` (the rows, for
+    /// `Axis(0)` of a 2D array) as elements; two subviews are equal if all their
+    /// elements are equal. The length of `axis` in the result is the number of
+    /// runs: if all subviews are equal it is one, and if no two consecutive
+    /// subviews are equal the result is a copy of `self`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let frames = array![[0, 1], [0, 1], [2, 2], [0, 1], [0, 1], [0, 1]];
+    /// assert_eq!(frames.dedup_consecutive_axis(Axis(0)),
+    ///            array![[0, 1], [2, 2], [0, 1]]);
+    ///
+    /// let labels = array![[1, 1, 2, 2, 2, 3, 1]];
+    /// assert_eq!(labels.dedup_consecutive_axis(Axis(1)), array![[1, 2, 3, 1]]);
+    /// ```
+    #[track_caller]
+    pub fn dedup_consecutive_axis(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + PartialEq,
+        S: Data,
+        D: RemoveAxis,
+    {
+        let mut keep = Vec::new();
+        let mut last_kept = None;
+        for (i, sub) in self.axis_iter(axis).enumerate() {
+            if last_kept.as_ref() != Some(&sub) {
+                keep.push(i);
+                last_kept = Some(sub);
+            }
+        }
+        self.select(axis, &keep)
+    }
+
+    /// Return the elements at the given flat indices, like `numpy.take` without an axis.
+    ///
+    /// The flat index of an element is its position in logical order, the order of
+    /// [`.iter()`](Self::iter), which is the row-major flattening of the array regardless of
+    /// its memory layout. Indices may be repeated and in any order; the result has one element
+    /// per index.
+    ///
+    /// This is the elementwise analogue of [`.select()`](Self::select), which picks whole
+    /// subviews along an axis.
+    ///
+    /// **Panics** if an index is out of bounds (not less than `self.len()`).
+    ///
+    /// ```
+    /// use ndarray::{array, aview1};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.take(aview1(&[5, 0, 4, 0])), array![6, 1, 5, 1]);
+    /// assert_eq!(a.t().take(aview1(&[1, 2])), array![4, 2]);
+    /// ```
+    #[track_caller]
+    pub fn take(&self, indices: ArrayView1<'_, usize>) -> Array1<A>
+    where
+        A: Clone,
+        S: Data,
+    {
+        let len = self.len();
+        indices.map(|&index| {
+            ndassert!(index < len, "take: flat index {} is out of bounds for an array of {} elements", index, len);
+            // safe because the index is in bounds, so the offset is within the array
+            unsafe {
+                (*self
+                    .ptr
+                    .as_ptr()
+                    .offset(flat_index_offset(&self.dim, &self.strides, index)))
+                .clone()
+            }
+        })
+    }
+
+    /// Assign `values[k]` to the element at flat index `indices[k]`, for each `k`, like
+    /// `numpy.put`.
+    ///
+    /// The flat index of an element is its position in logical order, the order of
+    /// [`.iter()`](Self::iter), regardless of the memory layout of the array. The values are
+    /// assigned in the order of `indices`, so if an index is repeated the last of its values
+    /// is the one that remains.
+    ///
+    /// **Panics** if `indices` and `values` have different lengths, or if an index is out of
+    /// bounds (not less than `self.len()`). Values are assigned up to the first index that is
+    /// out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, aview1};
+    ///
+    /// let mut a = array![[0, 0, 0],
+    ///                    [0, 0, 0]];
+    /// a.put(aview1(&[0, 4, 5, 0]), aview1(&[1, 2, 3, 4]));
+    /// assert_eq!(a, array![[4, 0, 0],
+    ///                      [0, 2, 3]]);
+    ///
+    /// // flat indices follow the logical order also for other layouts
+    /// a.view_mut().reversed_axes().put(aview1(&[2]), aview1(&[5]));
+    /// assert_eq!(a, array![[4, 5, 0],
+    ///                      [0, 2, 3]]);
+    /// ```
+    #[track_caller]
+    pub fn put(&mut self, indices: ArrayView1<'_, usize>, values: ArrayView1<'_, A>)
+    where
+        A: Clone,
+        S: DataMut,
+    {
+        ndassert!(
+            indices.len() == values.len(),
+            "put: the number of indices ({}) and values ({}) must be equal",
+            indices.len(),
+            values.len()
+        );
+        let len = self.len();
+        let ptr = self.as_mut_ptr();
+        for (&index, value) in indices.iter().zip(&values) {
+            ndassert!(index < len, "put: flat index {} is out of bounds for an array of {} elements", index, len);
+            // safe because the index is in bounds, so the offset is within the array
+            unsafe {
+                *ptr.offset(flat_index_offset(&self.dim, &self.strides, index)) = value.clone();
+            }
+        }
+    }
+
     /// Return a producer and iterable that traverses over the *generalized*
     /// rows of the array. For a 2D array these are the regular rows.
     ///
@@ -1279,6 +1492,37 @@ where
         AxisIter::new(self.view(), axis)
     }
 
+    /// Return an iterator that traverses over `axis` and yields each subview along it,
+    /// together with its index along `axis`.
+    ///
+    /// This is `.axis_iter(axis).enumerate()`; like [`.axis_iter()`](Self::axis_iter), the
+    /// iterator is double ended and knows its exact length, and iterating from the back
+    /// still yields the index along `axis` of each subview.
+    ///
+    /// Iterator element is `(usize, ArrayView<A, D::Smaller>)`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, aview1, Axis};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// let mut columns = a.axis_iter_indexed(Axis(1));
+    /// assert_eq!(columns.len(), 3);
+    /// let (i, last) = columns.next_back().unwrap();
+    /// assert_eq!((i, last), (2, aview1(&[3, 6])));
+    /// let (i, first) = columns.next().unwrap();
+    /// assert_eq!((i, first), (0, aview1(&[1, 4])));
+    /// ```
+    #[track_caller]
+    pub fn axis_iter_indexed(&self, axis: Axis) -> Enumerate<AxisIter<'_, A, D::Smaller>>
+    where
+        S: Data,
+        D: RemoveAxis,
+    {
+        self.axis_iter(axis).enumerate()
+    }
+
     /// Return an iterator that traverses over `axis`
     /// and yields each mutable subview along it.
     ///
@@ -1510,6 +1754,86 @@ where
         AxisWindows::new(self.view(), axis, window_size)
     }
 
+    /// Return an iterator over owned copies of the windows of length `window_size` along
+    /// `axis`, starting every `stride` elements.
+    ///
+    /// The window at index `k` is a copy of the elements at indices
+    /// `k * stride..k * stride + window_size` along `axis`, with the full extent of the other
+    /// axes; its shape is the shape of `self` with the length of `axis` replaced with
+    /// `window_size`. Where a window does not fit at the end of the axis, it is left out, so
+    /// for an axis of length `len` there are `(len - window_size) / stride + 1` windows, or
+    /// none if `window_size` is larger than `len`.
+    ///
+    /// Unlike the views of [`.axis_windows()`](Self::axis_windows), the windows don’t borrow
+    /// `self`, so they can for example be sent to other threads. Each window is copied when
+    /// it is produced, so overlapping windows (`stride < window_size`) copy each element up to
+    /// `window_size / stride` times (rounded up).
+    ///
+    /// **Panics** if `axis` is out of bounds or if `window_size` or `stride` is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// // six time steps of two features
+    /// let series = array![[0, 1, 2, 3, 4, 5],
+    ///                     [10, 11, 12, 13, 14, 15]];
+    /// let windows: Vec<_> = series.axis_windows_owned(Axis(1), 3, 2).collect();
+    /// assert_eq!(windows, vec![
+    ///     array![[0, 1, 2], [10, 11, 12]],
+    ///     array![[2, 3, 4], [12, 13, 14]],
+    /// ]);
+    /// ```
+    #[track_caller]
+    pub fn axis_windows_owned(&self, axis: Axis, window_size: usize, stride: usize) -> AxisWindowsOwned<'_, A, D>
+    where
+        S: Data,
+        A: Clone,
+    {
+        AxisWindowsOwned::new(self.view(), axis, window_size, stride)
+    }
+
+    /// Apply `f` to each sliding window of length `window_size` along `axis`,
+    /// and return an array of the results.
+    ///
+    /// The windows are the one-dimensional lanes along `axis`, starting at each
+    /// position where a full window fits, so the result has the same shape as
+    /// `self` except that the length of `axis` is `len - window_size + 1`
+    /// (or zero if `window_size` is larger than `len`). The result at index `k`
+    /// along `axis` is computed from the elements at indices `k..k + window_size`.
+    ///
+    /// This is useful for rolling statistics like moving sums, means or maxima.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `window_size` is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 3., 4.],
+    ///                [5., 6., 7., 8.]];
+    /// let means = a.rolling_axis(Axis(1), 3, |w| w.mean().unwrap());
+    /// assert_eq!(means, array![[2., 3.], [6., 7.]]);
+    /// ```
+    #[track_caller]
+    pub fn rolling_axis<B, F>(&self, axis: Axis, window_size: usize, mut f: F) -> Array<B, D>
+    where
+        F: FnMut(ArrayView1<'_, A>) -> B,
+        S: Data,
+    {
+        ndassert!(window_size > 0, "window_size must be greater than zero");
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = self.len_of(axis).saturating_sub(window_size - 1);
+        let mut result = Array::uninit(dim);
+        Zip::from(result.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|out, lane| {
+                Zip::from(out)
+                    .and(lane.windows(window_size))
+                    .for_each(|out, window| *out = MaybeUninit::new(f(window)));
+            });
+        // Safety: every element of result was written above.
+        unsafe { result.assume_init() }
+    }
+
     // Return (length, stride) for diagonal
     fn diag_params(&self) -> (Ix, Ixs)
     {
@@ -1573,13 +1897,71 @@ where
     ///
     /// Return `false` otherwise, i.e. the array is possibly not
     /// contiguous in memory, it has custom strides, etc.
+    ///
+    /// The strides of axes of length 1 don't matter, and an array with no
+    /// elements is always in standard layout. An axis with a negative stride
+    /// means the array is not in standard layout.
+    ///
+    /// ```
+    /// use ndarray::{array, s};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert!(a.is_standard_layout());
+    /// assert!(!a.t().is_standard_layout());
+    /// assert!(a.slice(s![1.., ..]).is_standard_layout());
+    /// assert!(!a.slice(s![.., ..;-1]).is_standard_layout());
+    /// ```
     pub fn is_standard_layout(&self) -> bool
     {
         dimension::is_layout_c(&self.dim, &self.strides)
     }
 
-    /// Return true if the array is known to be contiguous.
-    pub(crate) fn is_contiguous(&self) -> bool
+    /// Return `true` if the array data is laid out in contiguous “F order” in
+    /// memory (where the first index is the most rapidly varying).
+    ///
+    /// Return `false` otherwise. Like for
+    /// [`.is_standard_layout()`](Self::is_standard_layout), the strides of axes
+    /// of length 1 don't matter, an array with no elements is always in F order,
+    /// and an axis with a negative stride means the array is not in F order.
+    ///
+    /// One-dimensional arrays with stride 1 are both in standard and F order.
+    ///
+    /// ```
+    /// use ndarray::{Array2, ShapeBuilder};
+    ///
+    /// let a = Array2::<f32>::zeros((2, 3).f());
+    /// assert!(a.is_fortran_layout());
+    /// assert!(!a.is_standard_layout());
+    /// assert!(a.t().is_standard_layout());
+    /// ```
+    pub fn is_fortran_layout(&self) -> bool
+    {
+        dimension::is_layout_f(&self.dim, &self.strides)
+    }
+
+    /// Return `true` if the array is known to be contiguous in memory, in some
+    /// order of its axes and with positive or negative strides.
+    ///
+    /// An array is contiguous if its elements occupy a single block of memory
+    /// without gaps, so that they can be accessed as a slice in memory order with
+    /// [`.as_slice_memory_order()`](Self::as_slice_memory_order). The strides of
+    /// axes of length 1 don't matter. Arrays in standard or F order are contiguous,
+    /// but so are arrays with permuted or inverted axes of such arrays.
+    ///
+    /// For arrays with no elements, this may return `false` if they have custom strides.
+    ///
+    /// ```
+    /// use ndarray::{array, s, Axis};
+    ///
+    /// let mut a = array![[1, 2, 3], [4, 5, 6]];
+    /// a.swap_axes(0, 1);
+    /// a.invert_axis(Axis(0));
+    /// assert!(a.is_contiguous());
+    /// assert!(!a.is_standard_layout() && !a.is_fortran_layout());
+    ///
+    /// assert!(!a.slice(s![..;2, ..]).is_contiguous());
+    /// ```
+    pub fn is_contiguous(&self) -> bool
     {
         D::is_contiguous(&self.dim, &self.strides)
     }
@@ -1705,6 +2087,9 @@ where
 
     /// Return the array’s data as a slice, if it is contiguous and in standard order.
     /// Return `None` otherwise.
+    ///
+    /// For an owned `Array`, [`.make_contiguous()`](Array::make_contiguous) can be
+    /// used first to ensure that this returns `Some`.
     pub fn as_slice_mut(&mut self) -> Option<&mut [A]>
     where S: DataMut
     {
@@ -1862,6 +2247,53 @@ where
         }
     }
 
+    /// Return a view of the array with the new shape, without copying elements.
+    ///
+    /// The `new_shape` parameter is a dimension and an optional order, as for
+    /// [`.to_shape()`](Self::to_shape); the default order is `RowMajor`. Unlike `.to_shape()`,
+    /// this never copies: the result always borrows the array's existing data.
+    ///
+    /// Return `None` if the new shape doesn't have the same number of elements as the array's
+    /// current shape, or if the elements can not be viewed in the new shape with the given
+    /// order, for example because the array is not contiguous.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, s, Order};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(a.reshape_view((3, 2)).unwrap(), array![[1, 2], [3, 4], [5, 6]]);
+    /// assert_eq!(a.reshape_view(6).unwrap(), array![1, 2, 3, 4, 5, 6]);
+    ///
+    /// // wrong number of elements
+    /// assert!(a.reshape_view(4).is_none());
+    /// // column major order would need a copy
+    /// assert!(a.reshape_view((6, Order::ColumnMajor)).is_none());
+    /// assert!(a.t().reshape_view((6, Order::ColumnMajor)).is_some());
+    /// // the columns of `a` are not contiguous
+    /// assert!(a.slice(s![.., ..2]).reshape_view(4).is_none());
+    /// ```
+    pub fn reshape_view<E>(&self, new_shape: E) -> Option<ArrayView<'_, A, E::Dim>>
+    where
+        E: ShapeArg,
+        S: Data,
+    {
+        let (shape, order) = new_shape.into_shape_and_order();
+        let len = self.dim.size();
+        if size_of_shape_checked(&shape) != Ok(len) {
+            return None;
+        }
+        if len == 0 {
+            // Safe because the array and new shape are empty.
+            unsafe {
+                return Some(ArrayView::from_shape_ptr(shape, self.as_ptr()));
+            }
+        }
+        let strides = reshape_dim(&self.dim, &self.strides, &shape, order.unwrap_or(Order::RowMajor)).ok()?;
+        unsafe { Some(ArrayView::new(self.ptr, shape, strides)) }
+    }
+
     /// Transform the array into `shape`; any shape with the same number of
     /// elements is accepted, but the source array must be contiguous.
     ///
@@ -2095,14 +2527,32 @@ where
 
     /// Flatten the array to a one-dimensional array.
     ///
-    /// The array is returned as a `CowArray`; a view if possible, otherwise an owned array.
+    /// The elements are in logical (row-major) order, whatever the memory layout of the array.
+    /// This is like `numpy.ravel`: the array is returned as a `CowArray`; a view if possible,
+    /// otherwise an owned array.
+    ///
+    /// A view is possible if the elements in row-major order are equally spaced in memory, for
+    /// example if the array is in standard layout. Otherwise, for example for a transposed
+    /// array, the elements are copied.
+    ///
+    /// For an owned `Array1` in any case, use `.flatten().into_owned()`; to flatten an owned
+    /// array without copying when possible, use [`.into_flat()`](Self::into_flat).
     ///
     /// ```
-    /// use ndarray::{arr1, arr3};
+    /// use ndarray::{arr1, arr3, s};
     ///
     /// let array = arr3(&[[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
     /// let flattened = array.flatten();
     /// assert_eq!(flattened, arr1(&[1, 2, 3, 4, 5, 6, 7, 8]));
+    /// assert!(flattened.is_view());
+    ///
+    /// let columns = array.slice(s![.., .., 0]);
+    /// assert_eq!(columns.flatten(), arr1(&[1, 3, 5, 7]));
+    /// assert!(columns.flatten().is_view());
+    ///
+    /// let transposed = array.t();
+    /// assert_eq!(transposed.flatten(), arr1(&[1, 5, 3, 7, 2, 6, 4, 8]));
+    /// assert!(transposed.flatten().is_owned());
     /// ```
     pub fn flatten(&self) -> CowArray<'_, A, Ix1>
     where
@@ -2245,65 +2695,105 @@ where
         E: IntoDimension,
         S: Data,
     {
-        /// Return new stride when trying to grow `from` into shape `to`
-        ///
-        /// Broadcasting works by returning a "fake stride" where elements
-        /// to repeat are in axes with 0 stride, so that several indexes point
-        /// to the same element.
-        ///
-        /// **Note:** Cannot be used for mutable iterators, since repeating
-        /// elements would create aliasing pointers.
-        fn upcast<D: Dimension, E: Dimension>(to: &D, from: &E, stride: &E) -> Option<D>
-        {
-            // Make sure the product of non-zero axis lengths does not exceed
-            // `isize::MAX`. This is the only safety check we need to perform
-            // because all the other constraints of `ArrayBase` are guaranteed
-            // to be met since we're starting from a valid `ArrayBase`.
-            let _ = size_of_shape_checked(to).ok()?;
-
-            let mut new_stride = to.clone();
-            // begin at the back (the least significant dimension)
-            // size of the axis has to either agree or `from` has to be 1
-            if to.ndim() < from.ndim() {
-                return None;
-            }
+        let dim = dim.into_dimension();
 
-            {
-                let mut new_stride_iter = new_stride.slice_mut().iter_mut().rev();
-                for ((er, es), dr) in from
-                    .slice()
-                    .iter()
-                    .rev()
-                    .zip(stride.slice().iter().rev())
-                    .zip(new_stride_iter.by_ref())
-                {
-                    /* update strides */
-                    if *dr == *er {
-                        /* keep stride */
-                        *dr = *es;
-                    } else if *er == 1 {
-                        /* dead dimension, zero stride */
-                        *dr = 0
-                    } else {
-                        return None;
-                    }
-                }
+        // Note: zero strides are safe precisely because we return an read-only view
+        let broadcast_strides = upcast(&dim, &self.dim, &self.strides).ok()?;
+        unsafe { Some(ArrayView::new(self.ptr, dim, broadcast_strides)) }
+    }
 
-                /* set remaining strides to zero */
-                for dr in new_stride_iter {
-                    *dr = 0;
-                }
-            }
-            Some(new_stride)
-        }
+    /// Act like a larger size and/or shape array by *broadcasting*
+    /// into a larger shape, if possible.
+    ///
+    /// This is like [`.broadcast()`](Self::broadcast), but on failure it returns an
+    /// error that explains why the shapes are not compatible: it holds both
+    /// shapes and the axis that could not be broadcast, if any.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 3.]];
+    /// assert_eq!(a.try_broadcast((2, 3)).unwrap(), array![[1., 2., 3.], [1., 2., 3.]]);
+    ///
+    /// let err = a.try_broadcast((2, 4)).unwrap_err();
+    /// assert_eq!(err.axis(), Some(Axis(1)));
+    /// assert_eq!(err.from_shape(), &[1, 3]);
+    /// assert_eq!(err.to_shape(), &[2, 4]);
+    /// assert_eq!(err.to_string(),
+    ///            "BroadcastError: can not broadcast shape [1, 3] to [2, 4]: \
+    ///             axis 1 has length 3, which is not 1 or 4");
+    /// ```
+    pub fn try_broadcast<E>(&self, dim: E) -> Result<ArrayView<'_, A, E::Dim>, BroadcastError>
+    where
+        E: IntoDimension,
+        S: Data,
+    {
         let dim = dim.into_dimension();
 
         // Note: zero strides are safe precisely because we return an read-only view
-        let broadcast_strides = match upcast(&dim, &self.dim, &self.strides) {
-            Some(st) => st,
-            None => return None,
-        };
-        unsafe { Some(ArrayView::new(self.ptr, dim, broadcast_strides)) }
+        match upcast(&dim, &self.dim, &self.strides) {
+            Ok(broadcast_strides) => unsafe { Ok(ArrayView::new(self.ptr, dim, broadcast_strides)) },
+            Err(reason) => Err(BroadcastError::new(&self.dim, &dim, reason)),
+        }
+    }
+
+    /// Return a new array of the given shape, filled by repeating (tiling) `self` along each
+    /// axis, if possible.
+    ///
+    /// Each axis length of `shape` must be a multiple of the corresponding axis length of
+    /// `self`: an axis of length *n* is repeated *k* times to reach length *k n*. This differs
+    /// from [`.broadcast()`](Self::broadcast), which only repeats axes of length 1 but doesn't
+    /// copy: with tiling, the axes of `self` can have any length, and the result is an owned
+    /// array. Unlike broadcasting, tiling does not add axes; `shape` must have as many axes as
+    /// `self`.
+    ///
+    /// Return `None` if `shape` has a different number of axes, if an axis length is not a
+    /// multiple of the original (an axis of length 0 can only stay 0), or if the number of
+    /// elements would overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2],
+    ///                [3, 4]];
+    /// assert_eq!(
+    ///     a.broadcast_tile((4, 6)).unwrap(),
+    ///     array![[1, 2, 1, 2, 1, 2],
+    ///            [3, 4, 3, 4, 3, 4],
+    ///            [1, 2, 1, 2, 1, 2],
+    ///            [3, 4, 3, 4, 3, 4]]
+    /// );
+    /// assert!(a.broadcast_tile((4, 5)).is_none());
+    ///
+    /// // broadcasting only works with axes of length 1
+    /// assert!(a.broadcast((4, 6)).is_none());
+    /// ```
+    pub fn broadcast_tile<E>(&self, shape: E) -> Option<Array<A, D>>
+    where
+        E: IntoDimension<Dim = D>,
+        A: Clone,
+        S: Data,
+    {
+        let shape = shape.into_dimension();
+        if shape.ndim() != self.ndim() || size_of_shape_checked(&shape).is_err() {
+            return None;
+        }
+        // Broadcast a view with shape [k0, n0, k1, n1, ...] and merge the repeated axes
+        let mut tiled_dim = Vec::with_capacity(2 * self.ndim());
+        let mut view = self.view().into_dyn();
+        for (i, (&len, &target)) in self.shape().iter().zip(shape.slice()).enumerate() {
+            let repeats = match len {
+                0 if target == 0 => 1,
+                0 => return None,
+                _ if target % len == 0 => target / len,
+                _ => return None,
+            };
+            view = view.insert_axis(Axis(2 * i));
+            tiled_dim.push(repeats);
+            tiled_dim.push(len);
+        }
+        let tiled = view.broadcast(tiled_dim)?.as_standard_layout().into_owned();
+        Some(tiled.into_shape_with_order(shape).unwrap())
     }
 
     /// For two arrays or views, find their common shape if possible and
@@ -2366,6 +2856,30 @@ where
         self.strides.slice_mut().swap(ax, bx);
     }
 
+    /// Swap axes `ax` and `bx`, consuming the array and returning it.
+    ///
+    /// This is the by-value form of [`.swap_axes()`](Self::swap_axes), like
+    /// [`.reversed_axes()`](Self::reversed_axes) is for transposition, which is convenient in
+    /// method chains. It does not move any data, it just adjusts the array’s dimensions and
+    /// strides.
+    ///
+    /// **Panics** if the axes are out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Array};
+    ///
+    /// let a = Array::from_iter(0..6).into_shape_with_order((1, 2, 3)).unwrap();
+    /// let b = a.view().swapped_axes(0, 2).to_owned();
+    /// assert_eq!(b.shape(), &[3, 2, 1]);
+    /// assert_eq!(b, array![[[0], [3]], [[1], [4]], [[2], [5]]]);
+    /// ```
+    #[track_caller]
+    pub fn swapped_axes(mut self, ax: usize, bx: usize) -> ArrayBase<S, D>
+    {
+        self.swap_axes(ax, bx);
+        self
+    }
+
     /// Permute the axes.
     ///
     /// This does not move any data, it just adjusts the array’s dimensions
@@ -2416,17 +2930,60 @@ where
         unsafe { self.with_strides_dim(new_strides, new_dim) }
     }
 
-    /// Transpose the array by reversing axes.
+    /// Permute the axes in the order given by a runtime list of axis indices.
     ///
-    /// Transposition reverses the order of the axes (dimensions and strides)
-    /// while retaining the same data.
-    pub fn reversed_axes(mut self) -> ArrayBase<S, D>
-    {
-        self.dim.slice_mut().reverse();
-        self.strides.slice_mut().reverse();
-        self
-    }
-
+    /// This is like [`.permuted_axes()`](Self::permuted_axes), but `order` is a
+    /// slice whose length only needs to be known at runtime, and the result has
+    /// dynamic dimensionality. It does not move any data.
+    ///
+    /// *i* in the *j*-th place in `order` means `self`'s *i*-th axis becomes the
+    /// result's *j*-th axis.
+    ///
+    /// **Panics** if `order` is not a permutation of `0..self.ndim()`: if its
+    /// length is not `self.ndim()`, or if an axis is out of bounds or repeated.
+    ///
+    /// ```
+    /// use ndarray::{Array3, Axis};
+    ///
+    /// let a = Array3::<f64>::zeros((2, 3, 4));
+    ///
+    /// // move a chosen axis last
+    /// let axis = Axis(1);
+    /// let mut order: Vec<usize> = (0..a.ndim()).filter(|&i| i != axis.index()).collect();
+    /// order.push(axis.index());
+    /// assert_eq!(a.permute_axes_dyn(&order).shape(), &[2, 4, 3]);
+    /// ```
+    #[track_caller]
+    pub fn permute_axes_dyn(self, order: &[usize]) -> ArrayBase<S, IxDyn>
+    {
+        let ndim = self.ndim();
+        ndassert!(
+            order.len() == ndim,
+            "ndarray: axis order {:?} has length {}, but the array has {} axes",
+            order,
+            order.len(),
+            ndim
+        );
+        let mut seen = IxDyn::zeros(ndim);
+        for &axis in order {
+            ndassert!(axis < ndim, "ndarray: axis {} in order {:?} is out of bounds for {} axes", axis, order, ndim);
+            ndassert!(seen[axis] == 0, "ndarray: axis {} is repeated in order {:?}", axis, order);
+            seen[axis] = 1;
+        }
+        self.into_dyn().permuted_axes(order)
+    }
+
+    /// Transpose the array by reversing axes.
+    ///
+    /// Transposition reverses the order of the axes (dimensions and strides)
+    /// while retaining the same data.
+    pub fn reversed_axes(mut self) -> ArrayBase<S, D>
+    {
+        self.dim.slice_mut().reverse();
+        self.strides.slice_mut().reverse();
+        self
+    }
+
     /// Return a transposed view of the array.
     ///
     /// This is a shorthand for `self.view().reversed_axes()`.
@@ -2439,6 +2996,22 @@ where
     }
 
     /// Return an iterator over the length and stride of each axis.
+    ///
+    /// The iterator yields an [`AxisDescription`] for each axis in order, and is
+    /// double ended and exact size.
+    ///
+    /// ```
+    /// use ndarray::{Array3, Axis};
+    ///
+    /// let mut a = Array3::<f32>::zeros((3, 5, 4));
+    /// a.swap_axes(0, 2);
+    ///
+    /// // find the axis with the smallest stride
+    /// let inner = a.axes().min_by_key(|ax| ax.stride.abs()).unwrap();
+    /// assert_eq!(inner.axis, Axis(0));
+    /// assert_eq!(inner.len, 4);
+    /// assert_eq!(inner.stride, 1);
+    /// ```
     pub fn axes(&self) -> Axes<'_, D>
     {
         axes_of(&self.dim, &self.strides)
@@ -2474,6 +3047,189 @@ where
         }
     }
 
+    /// Reverse the order of the elements along `axis`, consuming the array and returning it.
+    ///
+    /// This is the by-value form of [`.invert_axis()`](Self::invert_axis), like `numpy.flip`
+    /// with a single axis. It does not move or copy any data, it just negates the stride of
+    /// `axis`, so call it on a view (`.view().flipped_axis(axis)`) to get a reversed view of
+    /// an array; for two-dimensional arrays, see also [`.flip_lr()`](ArrayBase::flip_lr) and
+    /// [`.flip_ud()`](ArrayBase::flip_ud).
+    ///
+    /// ***Panics*** if the axis is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// let b = a.view().flipped_axis(Axis(0));
+    /// assert_eq!(b, array![[4, 5, 6], [1, 2, 3]]);
+    /// assert_eq!(b.strides(), &[-3, 1]);
+    /// ```
+    #[track_caller]
+    pub fn flipped_axis(mut self, axis: Axis) -> ArrayBase<S, D>
+    {
+        self.invert_axis(axis);
+        self
+    }
+
+    /// Return a copy of the array with its elements shifted circularly by `shift` positions
+    /// along `axis`, like `numpy.roll` with a single axis.
+    ///
+    /// The element at index `i` along `axis` moves to index `(i + shift) mod n`, where `n` is
+    /// the length of `axis`; a negative `shift` moves the elements towards the start. See
+    /// [`.roll()`](Self::roll) to shift along every axis at once.
+    ///
+    /// ***Panics*** if the axis is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3, 4], [5, 6, 7, 8]];
+    /// assert_eq!(a.roll_axis(Axis(1), 1), array![[4, 1, 2, 3], [8, 5, 6, 7]]);
+    /// assert_eq!(a.roll_axis(Axis(1), -5), array![[2, 3, 4, 1], [6, 7, 8, 5]]);
+    /// ```
+    #[track_caller]
+    pub fn roll_axis(&self, axis: Axis, shift: isize) -> Array<A, D>
+    where
+        S: Data,
+        A: Clone,
+    {
+        let _ = self.len_of(axis);
+        self.rolled(|ax| if ax == axis.index() { shift } else { 0 })
+    }
+
+    /// Return a copy of the array with its elements shifted circularly by `shifts[i]`
+    /// positions along each axis `i`, like `numpy.roll` with a tuple of axes.
+    ///
+    /// This is the same as calling [`.roll_axis()`](Self::roll_axis) for each axis in turn,
+    /// but copies each element only once. Each shift is reduced modulo the length of its axis,
+    /// so for example shifting every axis by half its length swaps the halves of each axis, as
+    /// in an FFT shift.
+    ///
+    /// ***Panics*** if the length of `shifts` is not `self.ndim()`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.roll(&[1, -1]), array![[5, 6, 4],
+    ///                                     [2, 3, 1]]);
+    /// ```
+    #[track_caller]
+    pub fn roll(&self, shifts: &[isize]) -> Array<A, D>
+    where
+        S: Data,
+        A: Clone,
+    {
+        ndassert!(
+            shifts.len() == self.ndim(),
+            "ndarray: roll: {} shifts given for an array of {} dimensions",
+            shifts.len(),
+            self.ndim()
+        );
+        self.rolled(|ax| shifts[ax])
+    }
+
+    /// Return a copy of the array with the zero-frequency element of each axis shifted to its
+    /// center, like `numpy.fft.fftshift`.
+    ///
+    /// This is [`.roll()`](Self::roll) by `n / 2` (rounded down) along each axis of length
+    /// `n`. For an even length the two halves are swapped. For an odd length `n = 2m + 1`, the
+    /// last `m` elements move to the front, so that the zero-frequency element ends up at index
+    /// `m`, the center; the shift is then not its own inverse, use
+    /// [`.ifftshift()`](Self::ifftshift) to undo it.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // frequencies in the order of an FFT output of length 5 and 4
+    /// assert_eq!(array![0, 1, 2, -2, -1].fftshift(), array![-2, -1, 0, 1, 2]);
+    /// assert_eq!(array![0, 1, -2, -1].fftshift(), array![-2, -1, 0, 1]);
+    /// ```
+    pub fn fftshift(&self) -> Array<A, D>
+    where
+        S: Data,
+        A: Clone,
+    {
+        self.rolled(|ax| (self.len_of(Axis(ax)) / 2) as isize)
+    }
+
+    /// Return a copy of the array with the center element of each axis shifted back to the
+    /// start, the inverse of [`.fftshift()`](Self::fftshift), like `numpy.fft.ifftshift`.
+    ///
+    /// This is [`.roll()`](Self::roll) by `-(n / 2)` (with `n / 2` rounded down), or
+    /// equivalently by `(n + 1) / 2`, along each axis of length `n`. For an even length it is
+    /// the same as `.fftshift()`; for an odd length `n = 2m + 1`, the first `m` elements move
+    /// to the back, so that the center element at index `m` ends up at index 0.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let centered = array![[-2, -1, 0, 1, 2]];
+    /// assert_eq!(centered.ifftshift(), array![[0, 1, 2, -2, -1]]);
+    /// assert_eq!(centered.ifftshift().fftshift(), centered);
+    /// ```
+    pub fn ifftshift(&self) -> Array<A, D>
+    where
+        S: Data,
+        A: Clone,
+    {
+        self.rolled(|ax| -((self.len_of(Axis(ax)) / 2) as isize))
+    }
+
+    /// Circular shift by `shift(axis index)` along each axis
+    fn rolled(&self, shift: impl Fn(usize) -> isize) -> Array<A, D>
+    where
+        S: Data,
+        A: Clone,
+    {
+        let shifts: Vec<usize> = self
+            .shape()
+            .iter()
+            .enumerate()
+            .map(|(ax, &n)| {
+                if n == 0 {
+                    0
+                } else {
+                    shift(ax).rem_euclid(n as isize) as usize
+                }
+            })
+            .collect();
+        let rolled: Vec<usize> = (0..self.ndim()).filter(|&ax| shifts[ax] != 0).collect();
+
+        // Each rolled axis is split into the head `..n - s` of `self`, which moves to `s..`, and
+        // the tail `n - s..`, which moves to `..s`; copy each combination of parts as a block.
+        let mut out = Array::uninit(self.raw_dim());
+        for block in 0..1usize << rolled.len() {
+            let is_tail = |ax: usize| {
+                rolled
+                    .iter()
+                    .position(|&r| r == ax)
+                    .map(|k| block >> k & 1 == 1)
+            };
+            let src = self.slice_each_axis(|desc| {
+                let (ax, n) = (desc.axis.index(), desc.len);
+                match is_tail(ax) {
+                    None => Slice::from(..),
+                    Some(false) => Slice::from(..n - shifts[ax]),
+                    Some(true) => Slice::from(n - shifts[ax]..),
+                }
+            });
+            let dst = out.slice_each_axis_mut(|desc| {
+                let ax = desc.axis.index();
+                match is_tail(ax) {
+                    None => Slice::from(..),
+                    Some(false) => Slice::from(shifts[ax]..),
+                    Some(true) => Slice::from(..shifts[ax]),
+                }
+            });
+            src.assign_to(dst);
+        }
+        // all elements are written, since the blocks cover the whole array
+        unsafe { out.assume_init() }
+    }
+
     /// If possible, merge in the axis `take` to `into`.
     ///
     /// Returns `true` iff the axes are now merged.
@@ -2548,6 +3304,56 @@ where
         }
     }
 
+    /// Insert several new array axes of length one and return the result, which
+    /// has dynamic dimensionality.
+    ///
+    /// `positions` are the indices of the new axes *in the result*, which has
+    /// `self.ndim() + positions.len()` axes; the existing axes fill the other
+    /// places in their original order. So the order of `positions` doesn't
+    /// matter, and the positions don't shift as the axes are inserted. This is
+    /// the same as `numpy.expand_dims` with a tuple of axes.
+    ///
+    /// This does not move any data, it just adjusts the array’s dimensions
+    /// and strides.
+    ///
+    /// ***Panics*** if a position is out of bounds for the result, or if a
+    /// position is repeated.
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    ///
+    /// let a = Array2::<f64>::zeros((3, 4));
+    /// assert_eq!(a.view().insert_axes(&[0, 2]).shape(), &[1, 3, 1, 4]);
+    /// assert_eq!(a.view().insert_axes(&[3, 2]).shape(), &[3, 4, 1, 1]);
+    /// assert_eq!(a.insert_axes(&[]).shape(), &[3, 4]);
+    /// ```
+    #[track_caller]
+    pub fn insert_axes(self, positions: &[usize]) -> ArrayBase<S, IxDyn>
+    {
+        let ndim = self.ndim() + positions.len();
+        let mut is_new = IxDyn::zeros(ndim);
+        for &pos in positions {
+            ndassert!(pos < ndim, "ndarray: axis position {} is out of bounds for {} axes", pos, ndim);
+            ndassert!(is_new[pos] == 0, "ndarray: axis position {} is repeated", pos);
+            is_new[pos] = 1;
+        }
+        let mut dim = IxDyn::zeros(ndim);
+        let mut strides = IxDyn::zeros(ndim);
+        let mut old_axes = self.dim.slice().iter().zip(self.strides.slice());
+        for i in 0..ndim {
+            if is_new[i] == 1 {
+                dim[i] = 1;
+                strides[i] = 1;
+            } else {
+                let (&d, &s) = old_axes.next().unwrap();
+                dim[i] = d;
+                strides[i] = s;
+            }
+        }
+        // safe because new axes of length one do not affect memory layout
+        unsafe { self.with_strides_dim(strides, dim) }
+    }
+
     /// Remove array axis `axis` and return the result.
     ///
     /// This is equivalent to `.index_axis_move(axis, 0)` and makes most sense to use if the
@@ -2568,7 +3374,17 @@ where
 
     /// Perform an elementwise assigment to `self` from `rhs`.
     ///
-    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`, so
+    /// there is no need to call [`.broadcast()`](Self::broadcast) first. For example,
+    /// every row of a matrix can be set from a single one-dimensional array:
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let mut a = Array2::zeros((3, 2));
+    /// a.assign(&array![1., 2.]);
+    /// assert_eq!(a, array![[1., 2.], [1., 2.], [1., 2.]]);
+    /// ```
     ///
     /// **Panics** if broadcasting isn’t possible.
     #[track_caller]
@@ -2598,6 +3414,52 @@ where
         Zip::from(self).map_assign_into(to, A::clone);
     }
 
+    /// Perform an elementwise assigment to the sub-block of `self` that starts at
+    /// index `start` from `block`.
+    ///
+    /// The block is written with its first element (its “top-left corner”) at `start`;
+    /// this is the same as `self.slice_mut(s![r0..r0 + m, c0..c0 + n]).assign(block)`
+    /// for a 2D array, but works the same way for any dimensionality.
+    ///
+    /// **Panics** if the block doesn't fit within the bounds of `self` along every
+    /// axis, or if the number of axes differ.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let mut a = Array2::zeros((3, 4));
+    /// a.assign_block((1, 2), &array![[1, 2], [3, 4]]);
+    /// assert_eq!(a, array![[0, 0, 0, 0],
+    ///                      [0, 0, 1, 2],
+    ///                      [0, 0, 3, 4]]);
+    /// ```
+    #[track_caller]
+    pub fn assign_block<S2>(&mut self, start: D::Pattern, block: &ArrayBase<S2, D>)
+    where
+        S: DataMut,
+        A: Clone,
+        S2: Data<Elem = A>,
+    {
+        let start = start.into_dimension();
+        let fits = start.ndim() == self.ndim()
+            && block.ndim() == self.ndim()
+            && izip!(start.slice(), block.shape(), self.shape())
+                .all(|(&i, &block_len, &len)| i <= len && block_len <= len - i);
+        if !fits {
+            panic!(
+                "ndarray: block of shape {:?} at index {:?} does not fit in array of shape {:?}",
+                block.shape(),
+                start.slice(),
+                self.shape()
+            );
+        }
+        self.slice_each_axis_mut(|ax| {
+            let i = start[ax.axis.index()];
+            Slice::from(i..i + block.len_of(ax.axis))
+        })
+        .assign(block);
+    }
+
     /// Perform an elementwise assigment to `self` from element `x`.
     pub fn fill(&mut self, x: A)
     where
@@ -2607,6 +3469,107 @@ where
         self.map_inplace(move |elt| elt.clone_from(&x));
     }
 
+    /// Perform an elementwise assigment to `self` from the values returned by
+    /// calling `f` repeatedly.
+    ///
+    /// Unlike [`from_shape_fn`](ArrayBase::from_shape_fn), this overwrites
+    /// the elements of an existing array and `f` is not passed an index.
+    ///
+    /// Elements are visited in memory order if the array is contiguous, and
+    /// otherwise in an unspecified order that favours efficient traversal; use
+    /// [`.iter_mut()`](Self::iter_mut) if logical order is needed.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let mut a = Array::zeros((2, 3));
+    /// let mut counter = 0;
+    /// a.fill_with(|| {
+    ///     counter += 1;
+    ///     counter
+    /// });
+    /// assert_eq!(a, ndarray::array![[1, 2, 3], [4, 5, 6]]);
+    /// ```
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        S: DataMut,
+        F: FnMut() -> A,
+    {
+        self.map_inplace(move |elt| *elt = f());
+    }
+
+    /// Set every element of `self` where `mask` is `true` to `value`, leaving the other
+    /// elements unchanged.
+    ///
+    /// This is `a[mask] = value` in NumPy. If their shapes disagree, `mask` is broadcast to the
+    /// shape of `self`, like the argument of [`.assign()`](Self::assign).
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1, -2, 3],
+    ///                    [-4, 5, -6]];
+    /// let negative = a.mapv(|x| x < 0);
+    /// a.masked_fill(&negative, 0);
+    /// assert_eq!(a, array![[1, 0, 3], [0, 5, 0]]);
+    ///
+    /// // a mask of one row is broadcast to every row
+    /// a.masked_fill(&array![true, false, false], 9);
+    /// assert_eq!(a, array![[9, 0, 3], [9, 5, 0]]);
+    /// ```
+    #[track_caller]
+    pub fn masked_fill<S2, E>(&mut self, mask: &ArrayBase<S2, E>, value: A)
+    where
+        S: DataMut,
+        A: Clone,
+        S2: Data<Elem = bool>,
+        E: Dimension,
+    {
+        self.zip_mut_with(mask, move |elt, &m| {
+            if m {
+                elt.clone_from(&value);
+            }
+        });
+    }
+
+    /// Set every element of `self` where `mask` is `true` to the value returned by calling `f`,
+    /// leaving the other elements unchanged.
+    ///
+    /// This is [`.masked_fill()`](Self::masked_fill) with the values from `f`, like
+    /// [`.fill_with()`](Self::fill_with); `f` is called once for each `true` element of the
+    /// (broadcast) mask, in an unspecified order.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![0, 0, 0, 0];
+    /// let mut calls = 0;
+    /// a.masked_fill_with(&array![true, false, true, true], || {
+    ///     calls += 1;
+    ///     7
+    /// });
+    /// assert_eq!(a, array![7, 0, 7, 7]);
+    /// assert_eq!(calls, 3);
+    /// ```
+    #[track_caller]
+    pub fn masked_fill_with<S2, E, F>(&mut self, mask: &ArrayBase<S2, E>, mut f: F)
+    where
+        S: DataMut,
+        S2: Data<Elem = bool>,
+        E: Dimension,
+        F: FnMut() -> A,
+    {
+        self.zip_mut_with(mask, move |elt, &m| {
+            if m {
+                *elt = f();
+            }
+        });
+    }
+
     pub(crate) fn zip_mut_with_same_shape<B, S2, E, F>(&mut self, rhs: &ArrayBase<S2, E>, mut f: F)
     where
         S: DataMut,
@@ -2685,10 +3648,93 @@ where
         }
     }
 
+    /// Traverse two arrays in unspecified order, in lock step, calling the
+    /// closure `f` on each element pair, and return a new array with the
+    /// results.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    /// The result has the same shape as `self`. Its memory layout follows
+    /// `self` when `self` is in c- or f-order, unless `rhs` has the opposite
+    /// order, in which case c-order is used.
+    ///
+    /// This is a shorthand for `Zip::from(self).and(rhs).map_collect(f)` that
+    /// also broadcasts `rhs`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let data = array![[1., 2.], [3., 4.]];
+    /// let mask = array![true, false];
+    ///
+    /// let masked = data.zip_map(&mask, |&x, &keep| if keep { Some(x) } else { None });
+    /// assert_eq!(masked, array![[Some(1.), None], [Some(3.), None]]);
+    /// ```
+    #[track_caller]
+    pub fn zip_map<B, C, S2, E, F>(&self, rhs: &ArrayBase<S2, E>, f: F) -> Array<C, D>
+    where
+        S: Data,
+        S2: Data<Elem = B>,
+        E: Dimension,
+        F: FnMut(&A, &B) -> C,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        Zip::from(self).and(&rhs).map_collect(f)
+    }
+
+    /// Traverse two arrays in unspecified order, in lock step, and apply a
+    /// fold to each element pair, returning the resulting value.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// This is a shorthand for `Zip::from(self).and(rhs).fold(init, f)` that
+    /// also broadcasts `rhs`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let values = array![[1., 2.], [3., 4.]];
+    /// let weights = array![0.5, 2.];
+    ///
+    /// let weighted_sum = values.fold_with(&weights, 0., |acc, &x, &w| acc + x * w);
+    /// assert_eq!(weighted_sum, 14.);
+    /// ```
+    #[track_caller]
+    pub fn fold_with<'a, 'b, B, C, S2, E, F>(&'a self, rhs: &'b ArrayBase<S2, E>, init: C, f: F) -> C
+    where
+        S: Data,
+        S2: Data<Elem = B>,
+        E: Dimension,
+        F: FnMut(C, &'a A, &'b B) -> C,
+        A: 'a,
+        B: 'b,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        Zip::from(self).and(rhs).fold(init, f)
+    }
+
     /// Traverse the array elements and apply a fold,
     /// returning the resulting value.
     ///
-    /// Elements are visited in arbitrary order.
+    /// This is the general whole-array reduction, of which `.sum()` and
+    /// `.product()` are special cases.
+    ///
+    /// Elements are visited in arbitrary order: for efficiency, the order
+    /// follows the memory layout of the array, so it is not the logical order
+    /// for example for a transposed array. If `f` is not associative and
+    /// commutative, like floating point addition, the result can depend on the
+    /// memory layout; use [`.iter()`](Self::iter)`.fold()` to fold in logical
+    /// order.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let flags = array![[0b0001, 0b0100], [0b0001, 0b1000]];
+    /// assert_eq!(flags.fold(0, |acc, &x| acc | x), 0b1101);
+    /// ```
     pub fn fold<'a, F, B>(&'a self, init: B, f: F) -> B
     where
         F: FnMut(B, &'a A) -> B,
@@ -2729,7 +3775,7 @@ where
         S: Data,
     {
         unsafe {
-            if let Some(slc) = self.as_slice_memory_order() {
+            if let Some(slc) = self.as_slice_memory_order().filter(|slc| !slc.is_empty()) {
                 ArrayBase::from_shape_trusted_iter_unchecked(
                     self.dim.clone().strides(self.strides.clone()),
                     slc.iter(),
@@ -2789,6 +3835,125 @@ where
         self.map(move |x| f(x.clone()))
     }
 
+    /// Call the fallible function `f` by reference on each element and create a new
+    /// array with the new values.
+    ///
+    /// Elements are visited in arbitrary order. If `f` returns an error, no more
+    /// elements are visited, the new elements created so far are dropped, and the
+    /// error is returned.
+    ///
+    /// Return an array with the same shape as `self`.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&["1", "2", "3"]);
+    /// assert_eq!(a.try_map(|s| s.parse::<i32>()), Ok(arr1(&[1, 2, 3])));
+    ///
+    /// let b = arr1(&["1", "two", "3"]);
+    /// assert!(b.try_map(|s| s.parse::<i32>()).is_err());
+    /// ```
+    pub fn try_map<'a, B, E, F>(&'a self, f: F) -> Result<Array<B, D>, E>
+    where
+        F: FnMut(&'a A) -> Result<B, E>,
+        A: 'a,
+        S: Data,
+    {
+        unsafe {
+            if let Some(slc) = self.as_slice_memory_order().filter(|slc| !slc.is_empty()) {
+                ArrayBase::try_from_shape_trusted_iter_unchecked(
+                    self.dim.clone().strides(self.strides.clone()),
+                    slc.iter(),
+                    f,
+                )
+            } else {
+                ArrayBase::try_from_shape_trusted_iter_unchecked(self.dim.clone(), self.iter(), f)
+            }
+        }
+    }
+
+    /// Call the fallible function `f` by **v**alue on each element and create a new
+    /// array with the new values.
+    ///
+    /// Elements are visited in arbitrary order. If `f` returns an error, no more
+    /// elements are visited, the new elements created so far are dropped, and the
+    /// error is returned.
+    ///
+    /// Return an array with the same shape as `self`.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use std::convert::TryFrom;
+    ///
+    /// let a = arr2(&[[1, 2], [3, 4]]);
+    /// assert_eq!(a.try_mapv(u8::try_from), Ok(arr2(&[[1u8, 2], [3, 4]])));
+    ///
+    /// let b = arr2(&[[1, 2], [-3, 4]]);
+    /// assert!(b.try_mapv(u8::try_from).is_err());
+    /// ```
+    pub fn try_mapv<B, E, F>(&self, mut f: F) -> Result<Array<B, D>, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+        A: Clone,
+        S: Data,
+    {
+        self.try_map(move |x| f(x.clone()))
+    }
+
+    /// Convert each element to the numeric type `B` with `as` semantics, and create a new
+    /// array with the new values.
+    ///
+    /// This uses [`AsPrimitive`], which behaves like an `as` cast:
+    /// the conversion can lose information. Integers are truncated or wrapped to the width of
+    /// `B`, and floats are rounded toward zero and saturated when converted to integers (NaN
+    /// becomes 0).
+    ///
+    /// Use [`.try_cast_elem()`](Self::try_cast_elem) for a conversion that fails instead of
+    /// losing information. (Not to be confused with the `cast` method of raw views, which
+    /// reinterprets the element pointer without converting anything.)
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![-1.5f64, 2.7, 300., f64::NAN];
+    /// assert_eq!(a.cast_elem::<i32>(), array![-1, 2, 300, 0]);
+    /// assert_eq!(a.cast_elem::<u8>(), array![0, 2, 255, 0]);
+    /// assert_eq!(array![1u8, 2].cast_elem::<f32>(), array![1., 2.]);
+    /// ```
+    pub fn cast_elem<B>(&self) -> Array<B, D>
+    where
+        A: AsPrimitive<B>,
+        B: 'static + Copy,
+        S: Data,
+    {
+        self.mapv(|x| x.as_())
+    }
+
+    /// Convert each element to the type `B` with [`TryFrom`], and create a new array with the
+    /// new values.
+    ///
+    /// This is a lossless conversion: it fails if any element can not be represented exactly
+    /// in `B`.
+    ///
+    /// **Errors** with the error of the first element that fails to convert (in arbitrary
+    /// order).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![1i64, 200].try_cast_elem::<u8>(), Ok(array![1u8, 200]));
+    /// assert!(array![1i64, 256].try_cast_elem::<u8>().is_err());
+    /// assert!(array![-1i64].try_cast_elem::<u8>().is_err());
+    /// ```
+    pub fn try_cast_elem<B>(&self) -> Result<Array<B, D>, B::Error>
+    where
+        A: Clone,
+        B: TryFrom<A>,
+        S: Data,
+    {
+        self.try_mapv(B::try_from)
+    }
+
     /// Call `f` by **v**alue on each element, update the array with the new values
     /// and return it.
     ///
@@ -2906,6 +4071,34 @@ where
         self.fold((), move |(), elt| f(elt))
     }
 
+    /// Call the fallible function `f` for each element in the array, stopping at the first
+    /// error.
+    ///
+    /// Elements are visited in logical order, so the error returned is that of the first
+    /// failing element in that order.
+    ///
+    /// **Errors** with the first error returned by `f`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.], [f64::NAN, f64::INFINITY]];
+    /// let res = a.try_for_each(|&x| if x.is_finite() { Ok(()) } else { Err(x) });
+    /// assert!(res.unwrap_err().is_nan());
+    /// ```
+    pub fn try_for_each<'a, F, E>(&'a self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&'a A) -> Result<(), E>,
+        A: 'a,
+        S: Data,
+    {
+        if let Some(slc) = self.as_slice() {
+            slc.iter().try_for_each(f)
+        } else {
+            self.iter().try_for_each(f)
+        }
+    }
+
     /// Fold along an axis.
     ///
     /// Combine the elements of each subview with the previous using the `fold`
@@ -3002,6 +4195,42 @@ where
         self.slice_axis_inplace(axis, Slice::new(0, Some(-1), 1));
     }
 
+    /// Remove the `index`th elements along `axis`, replacing them with the last
+    /// elements along `axis`.
+    ///
+    /// This is like [`.remove_index()`](Self::remove_index), but it does not
+    /// preserve the order along `axis`: like [`Vec::swap_remove`], the removed
+    /// subview is swapped with the last one, and then the last one is cut off.
+    /// This only moves the elements of two subviews, not those of all the
+    /// subviews after `index`.
+    ///
+    /// Decreases the length of `axis` by one.
+    ///
+    /// ***Panics*** if `axis` is out of bounds<br>
+    /// ***Panics*** if not `index < self.len_of(axis)`.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut particles = array![[0., 0.], [1., 1.], [2., 2.], [3., 3.]];
+    /// particles.swap_remove_index(Axis(0), 1);
+    /// assert_eq!(particles, array![[0., 0.], [3., 3.], [2., 2.]]);
+    /// ```
+    #[track_caller]
+    pub fn swap_remove_index(&mut self, axis: Axis, index: usize)
+    where S: DataOwned + DataMut
+    {
+        let len = self.len_of(axis);
+        assert!(index < len, "index {} must be less than length of Axis({})", index, axis.index());
+        if index != len - 1 {
+            let (mut removed, last) = self.view_mut().split_at(axis, len - 1);
+            removed.collapse_axis(axis, index);
+            Zip::from(removed).and(last).for_each(std::mem::swap);
+        }
+        // then slice the axis in place to cut out the removed final element
+        self.slice_axis_inplace(axis, Slice::new(0, Some(-1), 1));
+    }
+
     /// Iterates over pairs of consecutive elements along the axis.
     ///
     /// The first argument to the closure is an element, and the second
@@ -3057,6 +4286,222 @@ where
             f(&*prev, &mut *curr)
         });
     }
+
+    /// Iterates over pairs of consecutive elements along the axis, also passing
+    /// the index of the second element along the axis.
+    ///
+    /// This is like [`.accumulate_axis_inplace()`](Self::accumulate_axis_inplace),
+    /// for recurrences that depend on the position. The closure is called as
+    /// `f(i, &prev, &mut curr)`, where `curr` is the element at index `i` along
+    /// the axis and `prev` is the one at index `i - 1`, already updated.
+    ///
+    /// Each lane along the axis is traversed from front to back, so `i` goes
+    /// from 1 to `self.len_of(axis) - 1`; the element at index 0 is the base case
+    /// and is left as it is. The order in which the lanes are visited is
+    /// unspecified.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// A first order filter `y[i] = x[i] + c[i] * y[i - 1]` with time-varying
+    /// coefficients `c`:
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let c = [0., 0.5, 0.5, 2.];
+    /// let mut y = array![[1., 1., 1., 1.],
+    ///                    [2., 0., 0., 0.]];
+    /// y.accumulate_axis_inplace_indexed(Axis(1), |i, &prev, curr| *curr += c[i] * prev);
+    /// assert_eq!(y, array![[1., 1.5, 1.75, 4.5],
+    ///                      [2., 1., 0.5, 1.]]);
+    /// ```
+    #[track_caller]
+    pub fn accumulate_axis_inplace_indexed<F>(&mut self, axis: Axis, mut f: F)
+    where
+        F: FnMut(usize, &A, &mut A),
+        S: DataMut,
+    {
+        let len = self.len_of(axis);
+        if len <= 1 {
+            return;
+        }
+        for mut lane in self.lanes_mut(axis) {
+            for i in 1..len {
+                let (prev, mut curr) = lane.view_mut().split_at(Axis(0), i);
+                f(i, &prev[i - 1], &mut curr[0]);
+            }
+        }
+    }
+
+    /// Return `true` if every lane along `axis` is sorted in non-decreasing order.
+    ///
+    /// Stops at the first pair of consecutive elements that is out of order.
+    /// Empty lanes and lanes of length one are sorted.
+    ///
+    /// For elements that are only partially ordered, such as floats with NaN,
+    /// the result is implementation-defined: NaN breaks the ordering.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 5, 6],
+    ///                [2, 4, 8]];
+    /// assert!(a.is_sorted_axis(Axis(1)));
+    /// assert!(!a.is_sorted_axis(Axis(0)));
+    /// ```
+    #[track_caller]
+    pub fn is_sorted_axis(&self, axis: Axis) -> bool
+    where
+        A: PartialOrd,
+        S: Data,
+    {
+        self.is_sorted_axis_by(axis, |a, b| a <= b)
+    }
+
+    /// Return `true` if every lane along `axis` is sorted according to `f`.
+    ///
+    /// `f(a, b)` is called for each pair of consecutive elements `a` and `b` of
+    /// a lane, in order, and should return `true` if they are in order; the
+    /// check stops as soon as it returns `false`. For example, `|a, b| a >= b`
+    /// checks for non-increasing lanes.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3., 2., 0.5],
+    ///                [9., 8., -1.]];
+    /// assert!(a.is_sorted_axis_by(Axis(1), |a, b| a >= b));
+    /// ```
+    #[track_caller]
+    pub fn is_sorted_axis_by<F>(&self, axis: Axis, mut f: F) -> bool
+    where
+        F: FnMut(&A, &A) -> bool,
+        S: Data,
+    {
+        if self.len_of(axis) <= 1 {
+            return true;
+        }
+        self.lanes(axis)
+            .into_iter()
+            .all(|lane| lane.iter().zip(lane.iter().skip(1)).all(|(a, b)| f(a, b)))
+    }
+
+    /// Return the partition point of each lane along `axis`: the index of the first element
+    /// for which `pred` returns `false`.
+    ///
+    /// Each lane must be partitioned by `pred`, so that it returns `true` for a (possibly
+    /// empty) prefix of the lane and `false` for the rest; then the result for the lane is
+    /// the length of that prefix, as by [`slice::partition_point`]. This is for example the
+    /// case for a sorted lane and `pred` comparing to a threshold. If a lane is not
+    /// partitioned, the result for it is unspecified, as the lane is searched by binary search.
+    ///
+    /// The result has the shape of `self` with `axis` removed.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let temperatures = array![[12., 15., 19., 23.],
+    ///                           [ 9., 10., 12., 14.]];
+    /// // the first index in each row exceeding a threshold of 14 degrees
+    /// let crossing = temperatures.partition_point_axis(Axis(1), |&t| t <= 14.);
+    /// assert_eq!(crossing, array![1, 4]);
+    /// ```
+    #[track_caller]
+    pub fn partition_point_axis<F>(&self, axis: Axis, mut pred: F) -> Array<usize, D::Smaller>
+    where
+        F: FnMut(&A) -> bool,
+        S: Data,
+        D: RemoveAxis,
+    {
+        Zip::from(self.lanes(axis)).map_collect(|lane| {
+            let (mut lo, mut hi) = (0, lane.len());
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if pred(&lane[mid]) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        })
+    }
+}
+
+/// Return new stride when trying to grow `from` into shape `to`
+///
+/// Broadcasting works by returning a "fake stride" where elements
+/// to repeat are in axes with 0 stride, so that several indexes point
+/// to the same element.
+///
+/// **Note:** Cannot be used for mutable iterators, since repeating
+/// elements would create aliasing pointers.
+fn upcast<D: Dimension, E: Dimension>(to: &D, from: &E, stride: &E) -> Result<D, BroadcastErrorReason>
+{
+    // Make sure the product of non-zero axis lengths does not exceed
+    // `isize::MAX`. This is the only safety check we need to perform
+    // because all the other constraints of `ArrayBase` are guaranteed
+    // to be met since we're starting from a valid `ArrayBase`.
+    if size_of_shape_checked(to).is_err() {
+        return Err(BroadcastErrorReason::Overflow);
+    }
+
+    let mut new_stride = to.clone();
+    // begin at the back (the least significant dimension)
+    // size of the axis has to either agree or `from` has to be 1
+    if to.ndim() < from.ndim() {
+        return Err(BroadcastErrorReason::TooFewAxes);
+    }
+
+    {
+        let mut new_stride_iter = new_stride.slice_mut().iter_mut().rev();
+        for (i, ((er, es), dr)) in from
+            .slice()
+            .iter()
+            .rev()
+            .zip(stride.slice().iter().rev())
+            .zip(new_stride_iter.by_ref())
+            .enumerate()
+        {
+            /* update strides */
+            if *dr == *er {
+                /* keep stride */
+                *dr = *es;
+            } else if *er == 1 {
+                /* dead dimension, zero stride */
+                *dr = 0
+            } else {
+                return Err(BroadcastErrorReason::Axis(to.ndim() - 1 - i));
+            }
+        }
+
+        /* set remaining strides to zero */
+        for dr in new_stride_iter {
+            *dr = 0;
+        }
+    }
+    Ok(new_stride)
+}
+
+/// Return the pointer offset of the element at the flat (row-major logical order) `index` of
+/// an array with the given shape and strides; `index` must be in bounds.
+fn flat_index_offset<D: Dimension>(dim: &D, strides: &D, index: usize) -> isize
+{
+    let mut rest = index;
+    let mut offset = 0;
+    for (&len, &stride) in dim.slice().iter().zip(strides.slice()).rev() {
+        offset += (rest % len) as isize * stride as isize;
+        rest /= len;
+    }
+    offset
 }
 
 /// Transmute from A to B.
@@ -3092,6 +4537,19 @@ mod tests
         assert_eq!(flattened, arr1(&[1, 2, 3, 4, 5, 6, 7, 8]));
     }
 
+    #[test]
+    fn flatten_view_or_copy()
+    {
+        let array = arr3(&[[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+        assert!(array.flatten().is_view());
+
+        let reversed = array.slice(s![..;-1, .., ..]);
+        assert_eq!(reversed.flatten(), arr1(&[5, 6, 7, 8, 1, 2, 3, 4]));
+        assert!(reversed.flatten().is_owned());
+        let outer = array.slice(s![..1, .., ..]);
+        assert!(outer.flatten().is_view());
+    }
+
     #[test]
     fn test_flatten_with_order()
     {
@@ -57,7 +57,7 @@ macro_rules! impl_binary_op(
 /// between `self` and `rhs`,
 /// and return the result.
 ///
-/// `self` must be an `Array` or `ArcArray`.
+/// `self` must be an `Array`, `ArcArray` or `CowArray`.
 ///
 /// If their shapes disagree, `self` is broadcast to their broadcast shape.
 ///
@@ -84,7 +84,7 @@ where
 /// between `self` and reference `rhs`,
 /// and return the result.
 ///
-/// `rhs` must be an `Array` or `ArcArray`.
+/// `rhs` must be an `Array`, `ArcArray` or `CowArray`.
 ///
 /// If their shapes disagree, `self` is broadcast to their broadcast shape,
 /// cloning the data if needed.
@@ -125,7 +125,7 @@ where
 /// between reference `self` and `rhs`,
 /// and return the result.
 ///
-/// `rhs` must be an `Array` or `ArcArray`.
+/// `rhs` must be an `Array`, `ArcArray` or `CowArray`.
 ///
 /// If their shapes disagree, `self` is broadcast to their broadcast shape,
 /// cloning the data if needed.
@@ -199,7 +199,7 @@ where
 /// between `self` and the scalar `x`,
 /// and return the result (based on `self`).
 ///
-/// `self` must be an `Array` or `ArcArray`.
+/// `self` must be an `Array`, `ArcArray` or `CowArray`.
 impl<A, S, D, B> $trt<B> for ArrayBase<S, D>
     where A: Clone + $trt<B, Output=A>,
           S: DataOwned<Elem=A> + DataMut,
@@ -1,6 +1,8 @@
+use alloc::vec;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
-use core::ptr::NonNull;
+use core::ptr::{self, NonNull};
+use std::marker::PhantomData;
 use std::mem;
 use std::mem::MaybeUninit;
 
@@ -551,6 +553,60 @@ where D: Dimension
         self.append(axis, array.insert_axis(axis).into_dimensionality::<D>().unwrap())
     }
 
+    /// Append several subviews to the array along an axis.
+    ///
+    /// This is like calling [`push`](Self::push) for each of the `subviews` in
+    /// order, but capacity for all of them is reserved up front, so that the
+    /// array is grown with (at most) one reallocation instead of many.
+    ///
+    /// The elements are cloned; `self` grows by the number of subviews along `axis`.
+    ///
+    /// ***Errors*** with a shape error if the shape of any of the subviews does not
+    /// match the shape of `self` with `axis` removed, or if the new shape would overflow
+    /// `isize`. All shapes are checked before anything is appended, so on error
+    /// `self` is unchanged.
+    ///
+    /// See [`append`](Self::append) for how the memory layout of `self` affects
+    /// the efficiency of the operation.
+    ///
+    /// ```rust
+    /// use ndarray::{Array, ArrayView, array, Axis};
+    ///
+    /// let mut a = Array::zeros((0, 3));
+    /// let rows = [array![1., 2., 3.], array![4., 5., 6.]];
+    /// a.extend_axis(Axis(0), rows.iter().map(|row| row.view())).unwrap();
+    /// a.extend_axis(Axis(0), vec![ArrayView::from(&[7., 8., 9.])]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     a,
+    ///     array![[1., 2., 3.],
+    ///            [4., 5., 6.],
+    ///            [7., 8., 9.]]);
+    ///
+    /// assert!(a.extend_axis(Axis(0), vec![ArrayView::from(&[1., 2.])]).is_err());
+    /// assert_eq!(a.nrows(), 3);
+    /// ```
+    pub fn extend_axis<'a, I>(&mut self, axis: Axis, subviews: I) -> Result<(), ShapeError>
+    where
+        I: IntoIterator<Item = ArrayView<'a, A, D::Smaller>>,
+        A: Clone + 'a,
+        D: RemoveAxis,
+    {
+        if self.ndim() == 0 {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+        }
+        let subviews: Vec<_> = subviews.into_iter().collect();
+        let subview_dim = self.raw_dim().remove_axis(axis);
+        if subviews.iter().any(|sub| sub.raw_dim() != subview_dim) {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
+        }
+        self.reserve(axis, subviews.len())?;
+        for sub in subviews {
+            self.push(axis, sub)?;
+        }
+        Ok(())
+    }
+
     /// Append an array to the array along an axis.
     ///
     /// The elements of `array` are cloned and extend the axis `axis` in the present array;
@@ -561,7 +617,8 @@ where D: Dimension
     /// the shape of `self` with `axis` removed must be the same as the shape of `array` with
     /// `axis` removed.
     ///
-    /// The memory layout of the `self` array matters for ensuring that the append is efficient.
+    /// The memory layout of `self` never causes an error or a panic; any layout can be appended
+    /// to. It only matters for ensuring that the append is efficient.
     /// Appending automatically changes memory layout of the array so that it is appended to
     /// along the "growing axis". However, if the memory layout needs adjusting, the array must
     /// reallocate and move memory.
@@ -573,6 +630,12 @@ where D: Dimension
     ///   *n-1*th axis in an F-layout array.
     /// - The axis has length 0 or 1 (It is converted to the new growing axis)
     ///
+    /// In addition, `axis` must not have a negative stride, and the array must use all of its
+    /// allocation (which is not the case after, for example, slicing it in place). Otherwise the
+    /// existing elements are first moved into a new allocation where `axis` is the growing axis,
+    /// which is O(*n*) in the number of elements of `self`; appends along the same axis after
+    /// that are efficient again.
+    ///
     /// Ensure appending is efficient by for example starting from an empty array and/or always
     /// appending to an array along the same axis.
     ///
@@ -859,6 +922,303 @@ where D: Dimension
 
         Ok(())
     }
+
+    /// Change the shape of the array to `shape` and set every element to `value`,
+    /// reusing the existing allocation when possible.
+    ///
+    /// The previous contents of the array are discarded. If the backing storage has
+    /// capacity for at least as many elements as `shape` requires, it is reused
+    /// without reallocating; otherwise it is grown. The strides are recomputed for
+    /// `shape`, in c- or f-order as requested by the shape argument.
+    ///
+    /// This is useful to avoid allocating a new array in each iteration of a
+    /// loop when the shape stays the same or shrinks.
+    ///
+    /// ***Panics*** if the product of non-zero axis lengths overflows `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Array2, ShapeBuilder};
+    ///
+    /// let mut a = Array2::<f64>::zeros((4, 5));
+    /// let ptr = a.as_ptr();
+    ///
+    /// a.resize_fill((2, 3), 1.);
+    /// assert_eq!(a, array![[1., 1., 1.], [1., 1., 1.]]);
+    /// assert_eq!(a.as_ptr(), ptr);
+    ///
+    /// a.resize_fill((3, 2).f(), 2.);
+    /// assert_eq!(a.strides(), &[1, 3]);
+    /// ```
+    pub fn resize_fill<Sh>(&mut self, shape: Sh, value: A)
+    where
+        A: Clone,
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        let shape = shape.into_shape_with_order();
+        let size = match dimension::size_of_shape_checked(&shape.dim) {
+            Ok(sz) => sz,
+            Err(_) => panic!("ndarray: Shape too large, product of non-zero axis lengths overflows isize"),
+        };
+        if D::NDIM == Some(0) {
+            // The shape has exactly one element, like the current array
+            self.fill(value);
+            return;
+        }
+        // If dropping or cloning elements panics, `self` is left as an empty array
+        let mut v = mem::replace(self, Array::empty()).data.into_vec();
+        v.clear();
+        v.resize(size, value);
+        // safe because the vector has the size required by the shape
+        unsafe {
+            *self = Array::from_shape_vec_unchecked(shape, v);
+        }
+    }
+
+    /// Release excess capacity of the backing storage.
+    ///
+    /// If the elements of the array make up the whole backing storage, which is
+    /// the case for any array that was not sliced in place, the storage is
+    /// shrunk like `Vec::shrink_to_fit` and no elements are moved or copied.
+    /// The strides are unchanged.
+    ///
+    /// Otherwise the backing storage also holds elements that are not part of
+    /// the array (for example after [`.slice_collapse()`](ArrayBase::slice_collapse)).
+    /// Then the array is compacted: its elements are moved into a new
+    /// allocation of exactly the required size, and the other elements are
+    /// dropped. The new memory layout is f-order if the array's layout leans
+    /// towards f-order (axis 0 has unit stride, like a sliced f-order array)
+    /// and c-order otherwise.
+    ///
+    /// ```rust
+    /// use ndarray::{array, s, Array2, Axis};
+    ///
+    /// let mut a = Array2::<i32>::zeros((0, 4));
+    /// a.reserve(Axis(0), 100).unwrap();
+    /// a.push_row((&[1, 2, 3, 4]).into()).unwrap();
+    /// a.shrink_to_fit();
+    /// assert_eq!(a.into_raw_vec_and_offset().0.capacity(), 4);
+    ///
+    /// let mut b = array![[1, 2, 3], [4, 5, 6]];
+    /// b.slice_collapse(s![.., 1..]);
+    /// b.shrink_to_fit();
+    /// assert_eq!(b, array![[2, 3], [5, 6]]);
+    /// assert_eq!(b.into_raw_vec_and_offset(), (vec![2, 3, 5, 6], Some(0)));
+    /// ```
+    pub fn shrink_to_fit(&mut self)
+    {
+        if self.len() == self.data.len() {
+            unsafe {
+                // shrink backing storage and update head ptr
+                let data_to_array_offset = if mem::size_of::<A>() != 0 {
+                    self.as_ptr().offset_from(self.data.as_ptr())
+                } else {
+                    0
+                };
+                debug_assert!(data_to_array_offset >= 0);
+                self.ptr = self.data.shrink_to_fit().offset(data_to_array_offset);
+            }
+        } else if D::NDIM == Some(0) {
+            // A zero-dimensional array has a single element; it can't be replaced by
+            // an empty array, so move the element out before dropping the rest.
+            let index = self.offset_from_alloc_to_logical_ptr().unwrap();
+            let mut v = mem::replace(&mut self.data, OwnedRepr::from(Vec::new())).into_vec();
+            let elt = v.swap_remove(index);
+            unsafe {
+                *self = Array::from_shape_vec_unchecked(self.raw_dim(), vec![elt]);
+            }
+            drop(v);
+        } else {
+            let is_f = self.layout_impl().tendency() < 0;
+            self.move_to_new_allocation(self.raw_dim().set_f(is_f));
+        }
+        debug_assert!(self.pointer_is_inbounds());
+    }
+
+    /// Make the array contiguous in standard layout (c-order), moving its elements
+    /// to a new allocation if necessary.
+    ///
+    /// If the array is already in standard layout, this does nothing. Otherwise,
+    /// a new buffer is allocated and the elements are moved into it (without
+    /// cloning them), and the strides are updated.
+    ///
+    /// Afterwards, [`.as_slice_mut()`](ArrayBase::as_slice_mut) is guaranteed to
+    /// return `Some`, which is useful before sorting or passing the data to
+    /// functions that need a contiguous slice.
+    ///
+    /// ```rust
+    /// use ndarray::array;
+    ///
+    /// let mut a = array![[1, 2, 3], [4, 5, 6]];
+    /// a.swap_axes(0, 1);
+    /// assert!(a.as_slice_mut().is_none());
+    ///
+    /// a.make_contiguous();
+    /// assert_eq!(a.as_slice_mut().unwrap(), &[1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn make_contiguous(&mut self)
+    {
+        if !self.is_standard_layout() {
+            self.move_to_new_allocation(self.raw_dim());
+        }
+    }
+
+    /// Split the array into two owned arrays, at `index` along `axis`.
+    ///
+    /// The first array holds the subviews before `index` and the second the subviews from
+    /// `index` onwards, like [`.split_at()`](ArrayBase::split_at) does for views. Each half
+    /// is a new allocation in standard layout, so the elements are cloned; the halves don't
+    /// borrow anything and can for example be sent to different threads.
+    ///
+    /// If `index` is zero the first half is empty, and if it is the length of `axis` the
+    /// second half is empty.
+    ///
+    /// **Panics** if `axis` or `index` is out of bounds.
+    ///
+    /// ```rust
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3], [4, 5, 6]];
+    /// let (left, right) = a.split_at_axis(Axis(1), 1);
+    /// assert_eq!(left, array![[1], [4]]);
+    /// assert_eq!(right, array![[2, 3], [5, 6]]);
+    /// ```
+    #[track_caller]
+    pub fn split_at_axis(self, axis: Axis, index: usize) -> (Self, Self)
+    where A: Clone
+    {
+        let (first, second) = self.view().split_at(axis, index);
+        (first.as_standard_layout().into_owned(), second.as_standard_layout().into_owned())
+    }
+
+    /// Consume the array, call `f` by **v**alue on each element, and return an
+    /// array of the new values that reuses the allocation of `self`.
+    ///
+    /// This requires that `A` and `B` have the same size and alignment, which is
+    /// checked at compile time. Each element is moved out, mapped, and the result
+    /// is written back to the same place in the buffer, which is then reinterpreted
+    /// as a buffer of `B`; the result has the same shape and strides as `self`.
+    ///
+    /// If the buffer holds elements that are not reachable from the array (for
+    /// example after in-place slicing), the array is first compacted into a new
+    /// allocation, as by [`.shrink_to_fit()`](Self::shrink_to_fit).
+    ///
+    /// Elements are visited in arbitrary order. If `f` panics, all remaining
+    /// elements, both of type `A` and `B`, are dropped.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1.5f32, -2.], [3., 4.25]];
+    /// let b = a.mapv_into_same_size(|x| x.to_bits());
+    /// assert_eq!(b, array![[1.5f32.to_bits(), (-2f32).to_bits()], [3f32.to_bits(), 4.25f32.to_bits()]]);
+    /// ```
+    ///
+    /// Using element types of different size is a compile time error:
+    ///
+    /// ```compile_fail
+    /// use ndarray::array;
+    ///
+    /// let a = array![1u8, 2, 3];
+    /// let b = a.mapv_into_same_size(|x| x as u16);
+    /// ```
+    pub fn mapv_into_same_size<B, F>(mut self, mut f: F) -> Array<B, D>
+    where F: FnMut(A) -> B
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = SameLayout::<A, B>::ASSERT;
+
+        if self.len() != self.data.len() {
+            self.shrink_to_fit();
+        }
+        debug_assert_eq!(self.len(), self.data.len());
+
+        let ArrayBase {
+            mut data,
+            ptr,
+            dim,
+            strides,
+        } = self;
+
+        // The owned repr releases ownership of all elements; the guard is responsible for
+        // dropping them if `f` panics, and the owned repr for the allocation.
+        let len = data.release_all_elements();
+        let mut guard = MapInPlaceGuard::<A, B> {
+            ptr: data.as_nonnull_mut().as_ptr(),
+            len,
+            done: 0,
+            marker: PhantomData,
+        };
+        unsafe {
+            while guard.done < len {
+                let elt = guard.ptr.add(guard.done);
+                let result = f(elt.read());
+                (elt as *mut B).write(result);
+                guard.done += 1;
+            }
+            mem::forget(guard);
+            data.set_len(len);
+            // Safety: A and B have the same size and alignment, and all elements are now B.
+            let data = data.data_subst::<B>();
+            ArrayBase::from_data_ptr(data, ptr.cast::<B>()).with_strides_dim(strides, dim)
+        }
+    }
+
+    /// Move the elements of the array into a new allocation with shape and layout `shape`,
+    /// which must be of the same shape as the array.
+    ///
+    /// ***Panics*** if `D` is zero-dimensional.
+    fn move_to_new_allocation(&mut self, shape: impl ShapeBuilder<Dim = D>)
+    {
+        let mut new_array = Self::uninit(shape);
+
+        // self -> old_self.
+        // dummy array -> self.
+        // old_self elements are moved -> new_array.
+        let old_self = mem::replace(self, Self::empty());
+        old_self.move_into_uninit(new_array.view_mut());
+
+        // new_array -> self.
+        unsafe {
+            *self = new_array.assume_init();
+        }
+    }
+}
+
+/// Compile time check that `A` and `B` have the same size and alignment.
+struct SameLayout<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> SameLayout<A, B>
+{
+    const ASSERT: () = assert!(
+        mem::size_of::<A>() == mem::size_of::<B>() && mem::align_of::<A>() == mem::align_of::<B>(),
+        "ndarray: element types must have the same size and alignment"
+    );
+}
+
+/// Drop guard for `mapv_into_same_size`.
+///
+/// The first `done` elements hold values of type `B`, the element at `done` has been moved
+/// from, and the rest hold values of type `A`.
+struct MapInPlaceGuard<A, B>
+{
+    ptr: *mut A,
+    len: usize,
+    done: usize,
+    marker: PhantomData<B>,
+}
+
+impl<A, B> Drop for MapInPlaceGuard<A, B>
+{
+    fn drop(&mut self)
+    {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr as *mut B, self.done));
+            if self.done < self.len {
+                let rest = self.len - self.done - 1;
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.add(self.done + 1), rest));
+            }
+        }
+    }
 }
 
 /// This drops all "unreachable" elements in `self_` given the data pointer and data length.
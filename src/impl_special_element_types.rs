@@ -8,8 +8,14 @@
 
 use std::mem::MaybeUninit;
 
+use num_complex::Complex;
+use num_traits::Num;
+use std::ops::Neg;
+
+use crate::error::{self, ShapeError};
 use crate::imp_prelude::*;
 use crate::RawDataSubst;
+use crate::Zip;
 
 /// Methods specific to arrays with `MaybeUninit` elements.
 ///
@@ -46,3 +52,196 @@ where
         ArrayBase::from_data_ptr(data, ptr).with_strides_dim(strides, dim)
     }
 }
+
+/// Methods specific to arrays with `Complex` elements.
+///
+/// These methods rely on the memory layout of `Complex<T>`, which is `repr(C)`
+/// with the real part followed by the imaginary part; an array of complex
+/// numbers can thus be viewed as interleaved real and imaginary components.
+///
+/// ***See also all methods for [`ArrayBase`]***
+impl<T, S, D> ArrayBase<S, D>
+where
+    S: RawData<Elem = Complex<T>>,
+    D: Dimension,
+{
+    /// Return a view of the real components of the elements, without copying.
+    ///
+    /// The view has the same shape as `self` and strides twice as large, in
+    /// units of `T`. See also [`ArrayView::split_complex`].
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(1., 2.), Complex64::new(3., 4.)];
+    /// assert_eq!(a.re(), array![1., 3.]);
+    /// assert_eq!(a.re().strides(), &[2]);
+    /// ```
+    pub fn re(&self) -> ArrayView<'_, T, D>
+    where S: Data
+    {
+        self.view().split_complex().re
+    }
+
+    /// Return a view of the imaginary components of the elements, without copying.
+    ///
+    /// The view has the same shape as `self` and strides twice as large, in
+    /// units of `T`. See also [`ArrayView::split_complex`].
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(1., 2.), Complex64::new(3., 4.)];
+    /// assert_eq!(a.im(), array![2., 4.]);
+    /// ```
+    pub fn im(&self) -> ArrayView<'_, T, D>
+    where S: Data
+    {
+        self.view().split_complex().im
+    }
+
+    /// Return a mutable view of the real components of the elements, without copying.
+    ///
+    /// See also [`ArrayViewMut::split_complex`] to get mutable views of both
+    /// components at the same time.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let mut a = array![Complex64::new(1., 2.), Complex64::new(3., 4.)];
+    /// a.re_mut().fill(0.);
+    /// assert_eq!(a, array![Complex64::new(0., 2.), Complex64::new(0., 4.)]);
+    /// ```
+    pub fn re_mut(&mut self) -> ArrayViewMut<'_, T, D>
+    where S: DataMut
+    {
+        self.view_mut().split_complex().re
+    }
+
+    /// Return a mutable view of the imaginary components of the elements, without copying.
+    ///
+    /// See also [`ArrayViewMut::split_complex`] to get mutable views of both
+    /// components at the same time.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let mut a = array![Complex64::new(1., 2.), Complex64::new(3., 4.)];
+    /// a.im_mut().mapv_inplace(|x| -x);
+    /// assert_eq!(a, array![Complex64::new(1., -2.), Complex64::new(3., -4.)]);
+    /// ```
+    pub fn im_mut(&mut self) -> ArrayViewMut<'_, T, D>
+    where S: DataMut
+    {
+        self.view_mut().split_complex().im
+    }
+
+    /// Return an array with the complex conjugate of each element.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![Complex64::new(1., 2.), Complex64::new(3., -4.)];
+    /// assert_eq!(a.conj(), array![Complex64::new(1., -2.), Complex64::new(3., 4.)]);
+    /// ```
+    pub fn conj(&self) -> Array<Complex<T>, D>
+    where
+        T: Clone + Num + Neg<Output = T>,
+        S: Data,
+    {
+        self.map(Complex::conj)
+    }
+
+    /// Replace each element with its complex conjugate, in place.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let mut a = array![Complex64::new(1., 2.), Complex64::new(3., -4.)];
+    /// a.conj_inplace();
+    /// assert_eq!(a, array![Complex64::new(1., -2.), Complex64::new(3., 4.)]);
+    /// ```
+    pub fn conj_inplace(&mut self)
+    where
+        T: Clone + Num + Neg<Output = T>,
+        S: DataMut,
+    {
+        self.im_mut().mapv_inplace(|x| -x);
+    }
+}
+
+/// Constructors for arrays with `Complex` elements.
+///
+/// ***See also all methods for [`ArrayBase`]***
+impl<T, S, D> ArrayBase<S, D>
+where
+    S: DataOwned<Elem = Complex<T>>,
+    D: Dimension,
+{
+    /// Create a complex array from arrays of its real and imaginary components.
+    ///
+    /// This is the inverse of [`.re()`](Self::re) and [`.im()`](Self::im): the element at each
+    /// index is `Complex::new(re[index], im[index])`.
+    ///
+    /// **Errors** if `re` and `im` have different shapes.
+    ///
+    /// ```
+    /// use ndarray::{array, Array1};
+    /// use num_complex::Complex64;
+    ///
+    /// let re = array![1., 3.];
+    /// let im = array![2., 4.];
+    /// let z = Array1::from_re_im(re.view(), im.view()).unwrap();
+    /// assert_eq!(z, array![Complex64::new(1., 2.), Complex64::new(3., 4.)]);
+    /// assert_eq!(z.re(), re);
+    /// assert_eq!(z.im(), im);
+    ///
+    /// assert!(Array1::<Complex64>::from_re_im(re.view(), im.slice(ndarray::s![..1])).is_err());
+    /// ```
+    pub fn from_re_im(re: ArrayView<'_, T, D>, im: ArrayView<'_, T, D>) -> Result<Self, ShapeError>
+    where T: Clone
+    {
+        if re.shape() != im.shape() {
+            return Err(error::incompatible_shapes(&re.raw_dim(), &im.raw_dim()));
+        }
+        Ok(Zip::from(&re)
+            .and(&im)
+            .map_collect_owned(|re, im| Complex::new(re.clone(), im.clone())))
+    }
+}
+
+/// Methods specific to two-dimensional arrays with `Complex` elements.
+///
+/// ***See also all methods for [`ArrayBase`]***
+impl<T, S> ArrayBase<S, Ix2>
+where S: RawData<Elem = Complex<T>>
+{
+    /// Return the conjugate transpose (Hermitian adjoint) of the matrix as a new array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use num_complex::Complex64;
+    ///
+    /// let a = array![
+    ///     [Complex64::new(1., 1.), Complex64::new(2., -1.)],
+    ///     [Complex64::new(0., 3.), Complex64::new(4., 0.)],
+    /// ];
+    /// assert_eq!(a.conj_t(), array![
+    ///     [Complex64::new(1., -1.), Complex64::new(0., -3.)],
+    ///     [Complex64::new(2., 1.), Complex64::new(4., 0.)],
+    /// ]);
+    /// ```
+    pub fn conj_t(&self) -> Array2<Complex<T>>
+    where
+        T: Clone + Num + Neg<Output = T>,
+        S: Data,
+    {
+        self.t().conj()
+    }
+}
@@ -222,6 +222,10 @@ where D: Dimension
 
     /// Convert the view into an `ArrayViewMut<'b, A, D>` where `'b` is a lifetime
     /// outlived by `'a'`.
+    ///
+    /// This consumes the view and keeps any lifetime up to `'a`. To reborrow a
+    /// mutable view temporarily and use it again afterwards, use
+    /// [`.view_mut()`](ArrayBase::view_mut) instead, which borrows the view itself.
     pub fn reborrow<'b>(self) -> ArrayViewMut<'b, A, D>
     where 'a: 'b
     {
@@ -26,6 +26,30 @@ where D: Dimension
 {
     /// Convert the view into an `ArrayView<'b, A, D>` where `'b` is a lifetime
     /// outlived by `'a'`.
+    ///
+    /// Unlike [`.view()`](ArrayBase::view), which borrows the view itself and so
+    /// gives a view that can't outlive the local variable holding it, this converts
+    /// the view (which is `Copy`) directly and keeps any lifetime up to `'a`. It
+    /// is useful for returning views from functions and where the lifetime
+    /// conversion needs to be spelled out, for example in generic code.
+    ///
+    /// ```
+    /// use ndarray::{array, ArrayView1};
+    ///
+    /// fn first_nonempty<'a>(a: ArrayView1<'a, i32>, b: ArrayView1<'a, i32>) -> ArrayView1<'a, i32>
+    /// {
+    ///     // `a.view()` would borrow the local `a` and can't be returned
+    ///     if a.is_empty() { b.reborrow() } else { a.reborrow() }
+    /// }
+    ///
+    /// let long_lived = array![1, 2, 3];
+    /// let v;
+    /// {
+    ///     let short_lived = array![4];
+    ///     v = first_nonempty(long_lived.view(), short_lived.view()).to_owned();
+    /// }
+    /// assert_eq!(v, array![1, 2, 3]);
+    /// ```
     pub fn reborrow<'b>(self) -> ArrayView<'b, A, D>
     where 'a: 'b
     {
@@ -37,6 +61,9 @@ where D: Dimension
     ///
     /// Note that while the method is similar to [`ArrayBase::as_slice()`], this method transfers
     /// the view's lifetime to the slice, so it is a bit more powerful.
+    ///
+    /// See [`into_slice_memory_order`](Self::into_slice_memory_order) for views that are
+    /// contiguous in some other order.
     pub fn to_slice(&self) -> Option<&'a [A]>
     {
         if self.is_standard_layout() {
@@ -62,6 +89,32 @@ where D: Dimension
         }
     }
 
+    /// Convert the view into a slice of its data in memory order, if it is contiguous.
+    /// Return `None` otherwise.
+    ///
+    /// Unlike [`to_slice`](Self::to_slice), which requires standard (row major)
+    /// layout and gives the elements in logical order, this accepts any layout where
+    /// the elements are adjacent in memory — for example column major (Fortran) order,
+    /// or axes with negative strides. The slice then lists the elements in the order
+    /// they are stored, which is useful when only the memory matters, as for FFI.
+    ///
+    /// ```
+    /// use ndarray::{array, ShapeBuilder, Array2};
+    ///
+    /// let a = Array2::from_shape_vec((2, 2).f(), vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(a, array![[1, 3], [2, 4]]);
+    /// assert_eq!(a.view().to_slice(), None);
+    /// assert_eq!(a.view().into_slice_memory_order(), Some(&[1, 2, 3, 4][..]));
+    ///
+    /// // a column is contiguous here, but a row is not
+    /// assert_eq!(a.column(0).into_slice_memory_order(), Some(&[1, 2][..]));
+    /// assert_eq!(a.row(0).into_slice_memory_order(), None);
+    /// ```
+    pub fn into_slice_memory_order(self) -> Option<&'a [A]>
+    {
+        self.to_slice_memory_order()
+    }
+
     /// Converts to a raw array view.
     #[inline]
     pub(crate) fn into_raw_view(self) -> RawArrayView<A, D>
@@ -98,6 +98,68 @@ where D: Dimension
     }
 }
 
+impl<'a, A, D> ArrayView<'a, A, D>
+where D: RemoveAxis
+{
+    /// Split off the first subview along axis 0, returning it together with a
+    /// view of the rest, or `None` if axis 0 has length zero.
+    ///
+    /// This is the array analogue of [`slice::split_first`]: the first part is
+    /// the subview at index 0 (with one dimension less), the second part is a view
+    /// of the indices `1..` along axis 0. No data is copied.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2], [3, 4], [5, 6]];
+    /// let (first, rest) = a.view().split_first().unwrap();
+    /// assert_eq!(first, array![1, 2]);
+    /// assert_eq!(rest, array![[3, 4], [5, 6]]);
+    ///
+    /// // process one row at a time
+    /// let mut rows = a.view();
+    /// let mut sums = Vec::new();
+    /// while let Some((row, rest)) = rows.split_first() {
+    ///     sums.push(row.sum());
+    ///     rows = rest;
+    /// }
+    /// assert_eq!(sums, [3, 7, 11]);
+    /// ```
+    pub fn split_first(self) -> Option<(ArrayView<'a, A, D::Smaller>, Self)>
+    {
+        if self.ndim() == 0 || self.len_of(Axis(0)) == 0 {
+            return None;
+        }
+        let (first, rest) = self.split_at(Axis(0), 1);
+        Some((first.index_axis_move(Axis(0), 0), rest))
+    }
+
+    /// Split off the last subview along axis 0, returning it together with a
+    /// view of the rest, or `None` if axis 0 has length zero.
+    ///
+    /// This is the array analogue of [`slice::split_last`]: the first part is
+    /// the subview at the last index (with one dimension less), the second part is
+    /// a view of all the other indices along axis 0. No data is copied.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1, 2], [3, 4], [5, 6]];
+    /// let (last, rest) = a.view().split_last().unwrap();
+    /// assert_eq!(last, array![5, 6]);
+    /// assert_eq!(rest, array![[1, 2], [3, 4]]);
+    /// ```
+    pub fn split_last(self) -> Option<(ArrayView<'a, A, D::Smaller>, Self)>
+    {
+        if self.ndim() == 0 || self.len_of(Axis(0)) == 0 {
+            return None;
+        }
+        let n = self.len_of(Axis(0));
+        let (rest, last) = self.split_at(Axis(0), n - 1);
+        Some((last.index_axis_move(Axis(0), 0), rest))
+    }
+}
+
 impl<'a, T, D> ArrayView<'a, Complex<T>, D>
 where D: Dimension
 {
@@ -172,6 +234,37 @@ where D: Dimension
     }
 }
 
+impl<'a, A, D> ArrayViewMut<'a, A, D>
+where D: RemoveAxis
+{
+    /// Split off the first subview along axis 0, returning it together with a
+    /// mutable view of the rest, or `None` if axis 0 has length zero.
+    ///
+    /// See [`ArrayView::split_first`] for more information.
+    pub fn split_first(self) -> Option<(ArrayViewMut<'a, A, D::Smaller>, Self)>
+    {
+        if self.ndim() == 0 || self.len_of(Axis(0)) == 0 {
+            return None;
+        }
+        let (first, rest) = self.split_at(Axis(0), 1);
+        Some((first.index_axis_move(Axis(0), 0), rest))
+    }
+
+    /// Split off the last subview along axis 0, returning it together with a
+    /// mutable view of the rest, or `None` if axis 0 has length zero.
+    ///
+    /// See [`ArrayView::split_last`] for more information.
+    pub fn split_last(self) -> Option<(ArrayViewMut<'a, A, D::Smaller>, Self)>
+    {
+        if self.ndim() == 0 || self.len_of(Axis(0)) == 0 {
+            return None;
+        }
+        let n = self.len_of(Axis(0));
+        let (rest, last) = self.split_at(Axis(0), n - 1);
+        Some((last.index_axis_move(Axis(0), 0), rest))
+    }
+}
+
 impl<'a, T, D> ArrayViewMut<'a, Complex<T>, D>
 where D: Dimension
 {
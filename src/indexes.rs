@@ -10,6 +10,7 @@ use crate::dimension::IntoDimension;
 use crate::split_at::SplitAt;
 use crate::zip::Offset;
 use crate::Axis;
+use crate::Ix1;
 use crate::Layout;
 use crate::NdProducer;
 use crate::{ArrayBase, Data};
@@ -50,6 +51,34 @@ where
     indices(array.dim())
 }
 
+/// Return an iterable of the indices along `axis` of the passed-in array,
+/// that is `0..array.len_of(axis)`.
+///
+/// Like the other `Indices`, this is also a one-dimensional producer, so it
+/// can be zipped with a producer over the subviews along `axis`, like
+/// `.axis_iter(axis)`.
+///
+/// **Panics** if `axis` is out of bounds.
+///
+/// ```
+/// use ndarray::{array, indices_of_axis, Axis, Zip};
+///
+/// let a = array![[1, 2, 3], [4, 5, 6]];
+/// let mut sums = [0; 2];
+/// Zip::from(a.axis_iter(Axis(0)))
+///     .and(indices_of_axis(&a, Axis(0)))
+///     .for_each(|row, i| sums[i] = row.sum());
+/// assert_eq!(sums, [6, 15]);
+/// ```
+#[track_caller]
+pub fn indices_of_axis<S, D>(array: &ArrayBase<S, D>, axis: Axis) -> Indices<Ix1>
+where
+    S: Data,
+    D: Dimension,
+{
+    indices(array.len_of(axis))
+}
+
 impl<D> Iterator for IndicesIter<D>
 where D: Dimension
 {
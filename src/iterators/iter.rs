@@ -14,6 +14,7 @@ pub use crate::iterators::{
     AxisIter,
     AxisIterMut,
     AxisWindows,
+    AxisWindowsOwned,
     ExactChunks,
     ExactChunksIter,
     ExactChunksIterMut,
@@ -32,7 +32,7 @@ use super::{Dimension, Ix, Ixs};
 pub use self::chunks::{ExactChunks, ExactChunksIter, ExactChunksIterMut, ExactChunksMut};
 pub use self::into_iter::IntoIter;
 pub use self::lanes::{Lanes, LanesMut};
-pub use self::windows::{AxisWindows, Windows};
+pub use self::windows::{AxisWindows, AxisWindowsOwned, Windows};
 
 use std::slice::{self, Iter as SliceIter, IterMut as SliceIterMut};
 
@@ -1596,3 +1596,20 @@ where
     debug_assert_eq!(size, result.len());
     result
 }
+
+/// Like `to_vec_mapped`, but stop at the first error returned by `f` and return it.
+///
+/// The elements that were already created are dropped along with the vector.
+pub fn try_to_vec_mapped<I, F, B, E>(iter: I, mut f: F) -> Result<Vec<B>, E>
+where
+    I: TrustedIterator + ExactSizeIterator,
+    F: FnMut(I::Item) -> Result<B, E>,
+{
+    let (size, _) = iter.size_hint();
+    let mut result = Vec::with_capacity(size);
+    for elt in iter {
+        result.push(f(elt)?);
+    }
+    debug_assert_eq!(size, result.len());
+    Ok(result)
+}
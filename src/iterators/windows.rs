@@ -249,6 +249,95 @@ where
     }
 }
 
+/// An iterator over owned copies of the windows along an axis
+///
+/// See [`.axis_windows_owned()`](ArrayBase::axis_windows_owned) for more
+/// information.
+pub struct AxisWindowsOwned<'a, A, D>
+{
+    base: ArrayView<'a, A, D>,
+    axis: Axis,
+    window: usize,
+    stride: usize,
+    /// Index of the next window from the front
+    index: usize,
+    /// One past the index of the next window from the back
+    end: usize,
+}
+
+impl<'a, A, D: Dimension> AxisWindowsOwned<'a, A, D>
+{
+    pub(crate) fn new(base: ArrayView<'a, A, D>, axis: Axis, window: usize, stride: usize) -> Self
+    {
+        let len = base.len_of(axis);
+        ndassert!(window > 0, "window size must be greater than zero");
+        ndassert!(stride > 0, "stride must be greater than zero");
+        let end = if len < window { 0 } else { (len - window) / stride + 1 };
+        AxisWindowsOwned {
+            base,
+            axis,
+            window,
+            stride,
+            index: 0,
+            end,
+        }
+    }
+
+    fn window_at(&self, index: usize) -> Array<A, D>
+    where A: Clone
+    {
+        let start = index * self.stride;
+        self.base
+            .slice_axis(self.axis, Slice::from(start..start + self.window))
+            .to_owned()
+    }
+}
+
+impl<'a, A, D> Iterator for AxisWindowsOwned<'a, A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+    type Item = Array<A, D>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.index == self.end {
+            return None;
+        }
+        self.index += 1;
+        Some(self.window_at(self.index - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, A, D> DoubleEndedIterator for AxisWindowsOwned<'a, A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+    fn next_back(&mut self) -> Option<Self::Item>
+    {
+        if self.index == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(self.window_at(self.end))
+    }
+}
+
+impl<'a, A, D> ExactSizeIterator for AxisWindowsOwned<'a, A, D>
+where
+    A: Clone,
+    D: Dimension,
+{
+}
+
 /// build the base array of the `Windows` and `AxisWindows` structs
 fn build_base<A, D>(a: ArrayView<A, D>, window: D, strides: D) -> ArrayView<A, D>
 where D: Dimension
@@ -137,9 +137,10 @@ pub use crate::dimension::{DimAdd, DimMax};
 
 pub use crate::dimension::IxDynImpl;
 pub use crate::dimension::NdIndex;
-pub use crate::error::{ErrorKind, ShapeError};
-pub use crate::indexes::{indices, indices_of};
+pub use crate::error::{BroadcastError, ErrorKind, LaneLengthError, ShapeError};
+pub use crate::indexes::{indices, indices_of, indices_of_axis};
 pub use crate::order::Order;
+pub use crate::pad::PadMode;
 pub use crate::slice::{MultiSliceArg, NewAxis, Slice, SliceArg, SliceInfo, SliceInfoElem, SliceNextDim};
 
 use crate::iterators::Baseiter;
@@ -169,6 +170,8 @@ mod array_serde;
 mod arrayformat;
 mod arraytraits;
 pub use crate::argument_traits::AssignElem;
+mod byteorder;
+pub use crate::byteorder::ByteSwap;
 mod data_repr;
 mod data_traits;
 
@@ -196,6 +199,7 @@ pub use crate::logspace::{logspace, Logspace};
 mod math_cell;
 mod numeric_util;
 mod order;
+mod pad;
 mod partial;
 mod shape_builder;
 #[macro_use]
@@ -686,9 +690,9 @@ pub type Ixs = isize;
 /// ### Binary Operators with Two Arrays
 ///
 /// Let `A` be an array or view of any kind. Let `B` be an array
-/// with owned storage (either `Array` or `ArcArray`).
-/// Let `C` be an array with mutable data (either `Array`, `ArcArray`
-/// or `ArrayViewMut`).
+/// with owned storage (either `Array`, `ArcArray` or `CowArray`).
+/// Let `C` be an array with mutable data (either `Array`, `ArcArray`,
+/// `CowArray` or `ArrayViewMut`).
 /// The following combinations of operands
 /// are supported for an arbitrary binary operator denoted by `@` (it can be
 /// `+`, `-`, `*`, `/` and so on).
@@ -717,6 +721,21 @@ pub type Ixs = isize;
 /// mutable += &view2;            // Updates `mutable` in-place.
 /// ```
 ///
+/// A `CowArray` of the view variant is converted to the owned variant (by
+/// cloning its elements) the first time it is updated, so `B @ A` and `C @= &A`
+/// copy the data at most once and leave the borrowed array unchanged.
+///
+/// ```
+/// use ndarray::{array, CowArray};
+///
+/// let data = array![1, 2];
+/// let mut cow = CowArray::from(&data);
+/// cow += &array![3, 4];             // Clones `data` into `cow`, then updates it.
+/// assert!(cow.is_owned());
+/// assert_eq!(cow, array![4, 6]);
+/// assert_eq!(data, array![1, 2]);
+/// ```
+///
 /// ### Binary Operators with Array and Scalar
 ///
 /// The trait [`ScalarOperand`] marks types that can be used in arithmetic
@@ -731,7 +750,7 @@ pub type Ixs = isize;
 /// ### Unary Operators
 ///
 /// Let `A` be an array or view of any kind. Let `B` be an array with owned
-/// storage (either `Array` or `ArcArray`). The following operands are supported
+/// storage (either `Array`, `ArcArray` or `CowArray`). The following operands are supported
 /// for an arbitrary unary operator denoted by `@` (it can be `-` or `!`).
 ///
 /// - `@&A` which produces a new `Array`
@@ -1163,6 +1182,19 @@ pub type Ixs = isize;
 /// it may be a good idea to add checks for these assumptions and possibly
 /// choose a different way to handle the empty case.
 ///
+/// For the two-dimensional case of a `Vec<Vec<A>>`, there is a `TryFrom`
+/// implementation that performs these checks and moves the elements:
+///
+/// ```rust
+/// use ndarray::{array, Array2};
+/// use std::convert::TryFrom;
+///
+/// let nested = vec![vec![1, 2, 3], vec![4, 5, 6]];
+/// let arr = Array2::try_from(nested)?;
+/// assert_eq!(arr, array![[1, 2, 3], [4, 5, 6]]);
+/// # Ok::<(), ndarray::ShapeError>(())
+/// ```
+///
 // # For implementors
 //
 // All methods must uphold the following constraints:
@@ -1589,11 +1621,15 @@ where
 #[cfg(feature = "rayon")]
 pub mod parallel;
 
+mod impl_0d;
 mod impl_1d;
+pub use crate::impl_1d::TrimMode;
 mod impl_2d;
+mod impl_3d;
 mod impl_dyn;
 
 mod numeric;
+pub use crate::numeric::{ConvMode, RankMethod};
 
 pub mod linalg;
 
@@ -1623,3 +1659,4 @@ pub(crate) fn is_aligned<T>(ptr: *const T) -> bool
 
 // Triangular constructors
 mod tri;
+pub use crate::tri::{diag_indices, tril_indices, triu_indices};
@@ -8,6 +8,7 @@
 
 use crate::imp_prelude::*;
 
+use crate::dimension::abs_index;
 #[cfg(feature = "blas")]
 use crate::dimension::offset_from_low_addr_ptr_to_logical_ptr;
 use crate::numeric_util;
@@ -18,6 +19,9 @@ use crate::{LinalgScalar, Zip};
 use alloc::vec::Vec;
 use std::any::TypeId;
 use std::mem::MaybeUninit;
+use std::ops::Add;
+
+use num_traits::Zero;
 
 use num_complex::Complex;
 use num_complex::{Complex32 as c32, Complex64 as c64};
@@ -67,6 +71,63 @@ where S: Data<Elem = A>
         Dot::dot(self, rhs)
     }
 
+    /// Compute the inner product of the vectors `self` and `rhs`, the sum of their elementwise
+    /// products; in einsum notation this is `i,i->`.
+    ///
+    /// This is [`.dot()`](Self::dot) of two vectors, with the argument type fixed to a vector.
+    /// As for `.dot()`, complex operands are not conjugated.
+    ///
+    /// **Panics** if the vectors have different lengths.<br>
+    /// *Note:* If enabled, uses blas `dot` for elements of `f32, f64` when memory
+    /// layout allows.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., 2., 3.];
+    /// assert_eq!(a.inner(&array![4., 5., 6.]), 32.);
+    /// ```
+    #[track_caller]
+    pub fn inner<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> A
+    where
+        S2: Data<Elem = A>,
+        A: LinalgScalar,
+    {
+        self.dot(rhs)
+    }
+
+    /// Compute the outer product of the vectors `self` and `rhs`.
+    ///
+    /// If `self` has length *M* and `rhs` has length *N*, the result has shape
+    /// *M* × *N*, and element `[i, j]` is `self[i] * rhs[j]`; in einsum
+    /// notation this is `i,j->ij`. The vectors may have different lengths.
+    ///
+    /// (The inner product `i,i->` is [`.inner()`](Self::inner).)
+    ///
+    /// **Panics** if the number of elements in the result would overflow
+    /// `isize`.<br>
+    /// *Note:* This is computed as the product of an *M* × 1 and a 1 × *N*
+    /// matrix, so if enabled, it uses blas `gemm` under the same conditions as
+    /// [`.dot()`](ArrayBase::dot) of two matrices.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![1., 2., 3.];
+    /// let b = array![1., 10.];
+    /// assert_eq!(a.outer(&b), array![[1., 10.], [2., 20.], [3., 30.]]);
+    /// ```
+    #[track_caller]
+    pub fn outer<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> Array2<A>
+    where
+        S2: Data<Elem = A>,
+        A: LinalgScalar,
+    {
+        let lhs = self.view().insert_axis(Axis(1));
+        let rhs = rhs.view().insert_axis(Axis(0));
+        lhs.dot(&rhs)
+    }
+
     fn dot_generic<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> A
     where
         S2: Data<Elem = A>,
@@ -225,7 +286,9 @@ where S: Data<Elem = A>
     /// If Rhs is two-dimensional, they array shapes must agree in the way that
     /// if `self` is *M* × *N*, then `rhs` is *N* × *K*.
     ///
-    /// Return a result array with shape *M* × *K*.
+    /// Return a result array with shape *M* × *K*; in einsum notation this is
+    /// `ij,jk->ik`. See also [`.matmul()`](Self::matmul) and, for stacks of
+    /// matrices, [`.bmm()`](ArrayBase::bmm).
     ///
     /// **Panics** if shapes are incompatible or the number of elements in the
     /// result would overflow `isize`.
@@ -253,6 +316,170 @@ where S: Data<Elem = A>
     {
         Dot::dot(self, rhs)
     }
+
+    /// Perform matrix multiplication of the matrices `self` and `rhs`; in
+    /// einsum notation this is `ij,jk->ik`.
+    ///
+    /// This is [`.dot()`](Self::dot) of two matrices, with the argument type
+    /// fixed to a matrix. If `self` is *M* × *N*, then `rhs` must be *N* × *K*,
+    /// and the result has shape *M* × *K*.
+    ///
+    /// **Panics** if shapes are incompatible or the number of elements in the
+    /// result would overflow `isize`.<br>
+    /// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64` under the
+    /// same conditions as `.dot()`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.],
+    ///                [0., 1.]];
+    /// let b = array![[1., 2., 0.],
+    ///                [2., 3., 1.]];
+    /// assert_eq!(a.matmul(&b), array![[5., 8., 2.], [2., 3., 1.]]);
+    /// ```
+    #[track_caller]
+    pub fn matmul<S2>(&self, rhs: &ArrayBase<S2, Ix2>) -> Array2<A>
+    where
+        S2: Data<Elem = A>,
+        A: LinalgScalar,
+    {
+        self.dot(rhs)
+    }
+
+    /// Raise the square matrix `self` to the power `n` by repeated matrix
+    /// multiplication.
+    ///
+    /// This uses exponentiation by squaring, so it performs *O*(log *n*)
+    /// matrix multiplications. `n = 0` gives the identity matrix.
+    ///
+    /// **Panics** if `self` is not square.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let fib = array![[1, 1],
+    ///                  [1, 0]];
+    /// assert_eq!(fib.matrix_power(10), array![[89, 55], [55, 34]]);
+    /// assert_eq!(fib.matrix_power(0), Array2::eye(2));
+    /// ```
+    #[track_caller]
+    pub fn matrix_power(&self, n: usize) -> Array2<A>
+    where A: LinalgScalar
+    {
+        ndassert!(
+            self.is_square(),
+            "ndarray: matrix_power requires a square matrix, but the shape is {:?}",
+            self.shape()
+        );
+        match n {
+            0 => return Array2::eye(self.nrows()),
+            1 => return self.to_owned(),
+            _ => {}
+        }
+
+        // Square the base for each bit of `n` and multiply in the set bits
+        let mut base = self.dot(self);
+        let mut result = if n & 1 == 1 { Some(self.to_owned()) } else { None };
+        let mut n = n >> 1;
+        loop {
+            if n & 1 == 1 {
+                result = Some(match result {
+                    Some(r) => r.dot(&base),
+                    None => base.clone(),
+                });
+            }
+            n >>= 1;
+            if n == 0 {
+                break;
+            }
+            base = base.dot(&base);
+        }
+        result.unwrap()
+    }
+}
+
+impl<A, S> ArrayBase<S, Ix3>
+where S: Data<Elem = A>
+{
+    /// Return the trace of each matrix in a stack of square matrices.
+    ///
+    /// `self` is interpreted as a batch of *B* matrices of shape *N* × *N*,
+    /// indexed by the first axis; element `i` of the result is the sum of the
+    /// diagonal of `self.index_axis(Axis(0), i)`.
+    ///
+    /// **Panics** if the last two axes don't have the same length.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[[1, 2], [3, 4]],
+    ///                [[5, 6], [7, 8]],
+    ///                [[0, 1], [1, 0]]];
+    /// assert_eq!(a.batched_trace(), array![5, 13, 0]);
+    /// ```
+    #[track_caller]
+    pub fn batched_trace(&self) -> Array1<A>
+    where A: Clone + Add<Output = A> + Zero
+    {
+        let (_, n, m) = self.dim();
+        ndassert!(
+            n == m,
+            "ndarray: batched_trace requires square matrices, but they are {} × {}",
+            n,
+            m
+        );
+        Zip::from(self.outer_iter()).map_collect(|mat| mat.diag().sum())
+    }
+
+    /// Perform batched matrix multiplication of the stacks of matrices `self`
+    /// and `rhs`.
+    ///
+    /// Both arrays are interpreted as batches of matrices indexed by the first
+    /// axis: if `self` is *B* × *M* × *N*, then `rhs` must be *B* × *N* × *K*,
+    /// and the result is *B* × *M* × *K* where matrix `i` is the product of
+    /// matrix `i` of `self` with matrix `i` of `rhs`. In einsum notation this
+    /// is `bij,bjk->bik`.
+    ///
+    /// **Panics** if shapes are incompatible or the number of elements in the
+    /// result would overflow `isize`.<br>
+    /// *Note:* Each product is computed like [`general_mat_mul`], so if enabled,
+    /// it uses blas `gemm` once per matrix under the same conditions; this
+    /// requires the matrices of `self` and `rhs` to be c- or f-contiguous, as
+    /// they are for example in a c-order array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[[1, 2], [3, 4]],
+    ///                [[1, 0], [0, 2]]];
+    /// let b = array![[[1], [1]],
+    ///                [[5], [6]]];
+    /// assert_eq!(a.bmm(&b), array![[[3], [7]], [[5], [12]]]);
+    /// ```
+    #[track_caller]
+    pub fn bmm<S2>(&self, rhs: &ArrayBase<S2, Ix3>) -> Array3<A>
+    where
+        S2: Data<Elem = A>,
+        A: LinalgScalar,
+    {
+        let ((b, m, k), (b2, k2, n)) = (self.dim(), rhs.dim());
+        if b != b2 {
+            panic!(
+                "ndarray: inputs with {} and {} matrices are not compatible for batched matrix multiplication",
+                b, b2
+            );
+        }
+        if k != k2 || m.checked_mul(n).is_none() {
+            dot_shape_error(m, k, k2, n);
+        }
+        let mut c = Array3::zeros((b, m, n));
+        Zip::from(self.outer_iter())
+            .and(rhs.outer_iter())
+            .and(c.outer_iter_mut())
+            .for_each(|a, b, mut c| mat_mul_impl(A::one(), &a, &b, A::zero(), &mut c));
+        c
+    }
 }
 
 impl<A, S, S2> Dot<ArrayBase<S2, Ix2>> for ArrayBase<S, Ix2>
@@ -364,6 +591,110 @@ where
     {
         self.zip_mut_with(rhs, move |y, &x| *y = *y + (alpha * x));
     }
+
+    /// Tensor contraction of `self` and `rhs` over the given pairs of axes.
+    ///
+    /// Axis `lhs_axes[i]` of `self` is summed against axis `rhs_axes[i]` of `rhs`, like
+    /// `numpy.tensordot(self, rhs, axes=(lhs_axes, rhs_axes))`. Negative axis indices count
+    /// from the last axis, so `-1` is the last axis.
+    ///
+    /// The axes of the result are the remaining axes of `self` followed by the remaining axes of
+    /// `rhs`, each in their original order. The contraction is computed as one matrix
+    /// multiplication, which copies the operands first if their layout requires it.
+    ///
+    /// **Panics** if the axis lists differ in length, if an axis is out of bounds or repeated
+    /// within its list, or if the lengths of paired axes differ.
+    ///
+    /// ```
+    /// use ndarray::{array, Array};
+    ///
+    /// let a = array![[1, 2], [3, 4], [5, 6]];
+    /// let b = array![[1, 0, 2], [0, 1, 3]];
+    ///
+    /// // the matrix product contracts the last axis of `a` with the first axis of `b`
+    /// assert_eq!(a.tensordot(&b, &[-1], &[0]), a.dot(&b).into_dyn());
+    /// assert_eq!(a.tensordot(&b, &[0], &[1]), a.t().dot(&b.t()).into_dyn());
+    ///
+    /// // contract two pairs of axes at once
+    /// let x = Array::from_iter(0..24).into_shape_with_order((2, 3, 4)).unwrap();
+    /// let y = Array::from_iter(0..24).into_shape_with_order((3, 2, 4)).unwrap();
+    /// let z = x.tensordot(&y, &[0, 1], &[1, 0]);
+    /// assert_eq!(z.shape(), &[4, 4]);
+    ///
+    /// // contracting over all axes is the sum of the elementwise product
+    /// let d = x.tensordot(&x, &[0, 1, 2], &[0, 1, 2]);
+    /// assert_eq!(d[[]], (0..24).map(|x| x * x).sum());
+    /// ```
+    #[track_caller]
+    pub fn tensordot<S2, E>(&self, rhs: &ArrayBase<S2, E>, lhs_axes: &[isize], rhs_axes: &[isize]) -> Array<A, IxDyn>
+    where
+        S2: Data<Elem = A>,
+        A: LinalgScalar,
+        E: Dimension,
+    {
+        ndassert!(
+            lhs_axes.len() == rhs_axes.len(),
+            "tensordot: {} axes of the left operand can not be paired with {} axes of the right operand",
+            lhs_axes.len(),
+            rhs_axes.len()
+        );
+        let lhs_axes = tensordot_axes(self.ndim(), lhs_axes);
+        let rhs_axes = tensordot_axes(rhs.ndim(), rhs_axes);
+        for (&i, &j) in lhs_axes.iter().zip(&rhs_axes) {
+            ndassert!(
+                self.len_of(Axis(i)) == rhs.len_of(Axis(j)),
+                "tensordot: axis {} of length {} can not be contracted with axis {} of length {}",
+                i,
+                self.len_of(Axis(i)),
+                j,
+                rhs.len_of(Axis(j))
+            );
+        }
+        let lhs_free: Vec<usize> = (0..self.ndim()).filter(|i| !lhs_axes.contains(i)).collect();
+        let rhs_free: Vec<usize> = (0..rhs.ndim()).filter(|i| !rhs_axes.contains(i)).collect();
+        let m = lhs_free
+            .iter()
+            .map(|&i| self.len_of(Axis(i)))
+            .product::<usize>();
+        let k = lhs_axes
+            .iter()
+            .map(|&i| self.len_of(Axis(i)))
+            .product::<usize>();
+        let n = rhs_free
+            .iter()
+            .map(|&i| rhs.len_of(Axis(i)))
+            .product::<usize>();
+        let mut shape: Vec<usize> = lhs_free.iter().map(|&i| self.len_of(Axis(i))).collect();
+        shape.extend(rhs_free.iter().map(|&i| rhs.len_of(Axis(i))));
+
+        // Move the contracted axes innermost in `self` and outermost in `rhs`
+        let lhs_order: Vec<usize> = lhs_free.iter().chain(&lhs_axes).cloned().collect();
+        let rhs_order: Vec<usize> = rhs_axes.iter().chain(&rhs_free).cloned().collect();
+        let lhs = self.view().into_dyn().permuted_axes(lhs_order);
+        let rhs = rhs.view().into_dyn().permuted_axes(rhs_order);
+        let lhs = lhs.to_shape((m, k)).unwrap();
+        let rhs = rhs.to_shape((k, n)).unwrap();
+        lhs.dot(&rhs).into_shape_clone(shape).unwrap()
+    }
+}
+
+/// Convert the contraction axes of `tensordot` to unsigned indices and check them.
+#[track_caller]
+fn tensordot_axes(ndim: usize, axes: &[isize]) -> Vec<usize>
+{
+    let mut res = Vec::with_capacity(axes.len());
+    for &axis in axes {
+        ndassert!(
+            axis < ndim as isize && axis >= -(ndim as isize),
+            "tensordot: axis {} is out of bounds for an array with {} axes",
+            axis,
+            ndim
+        );
+        let axis = abs_index(ndim, axis);
+        ndassert!(!res.contains(&axis), "tensordot: axis {} is repeated", axis);
+        res.push(axis);
+    }
+    res
 }
 
 // mat_mul_impl uses ArrayView arguments to send all array kinds into
@@ -717,6 +1048,107 @@ unsafe fn general_mat_vec_mul_impl<A, S1, S2>(
     }
 }
 
+impl<A, S> ArrayBase<S, Ix2>
+where S: DataMut<Elem = A>
+{
+    /// Rank-1 update of the matrix in place.
+    ///
+    /// Compute A ← A + α x yᵀ
+    ///
+    /// where A is `self`, a *M* × *N* matrix, x is an *M*-element and y an
+    /// *N*-element vector. This adds the outer product of `x` and `y` scaled
+    /// by `alpha` without allocating it. The elements are not conjugated.
+    ///
+    /// ***Panics*** if array shapes are not compatible<br>
+    /// *Note:* If enabled, uses blas `ger` (`geru` for complex numbers) for
+    /// elements of `f32, f64, c32, c64` when memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let mut gram = Array2::<f64>::zeros((2, 2));
+    /// for x in [array![1., 2.], array![0., 1.]] {
+    ///     gram.ger(1., &x, &x);
+    /// }
+    /// assert_eq!(gram, array![[1., 2.], [2., 5.]]);
+    /// ```
+    #[track_caller]
+    pub fn ger<S1, S2>(&mut self, alpha: A, x: &ArrayBase<S1, Ix1>, y: &ArrayBase<S2, Ix1>)
+    where
+        S1: Data<Elem = A>,
+        S2: Data<Elem = A>,
+        A: LinalgScalar,
+    {
+        let (m, n) = self.dim();
+        let (m2, n2) = (x.len(), y.len());
+        if m != m2 || n != n2 {
+            ger_shape_error(m, n, m2, n2);
+        }
+        let mut a = self.view_mut();
+
+        #[cfg(feature = "blas")]
+        macro_rules! ger {
+            ($ty:ty, $ger:ident, $alpha:expr) => {
+                if same_type::<A, $ty>() {
+                    if let Some(layout) = get_blas_compatible_layout(&a) {
+                        if blas_compat_1d::<$ty, _>(x) && blas_compat_1d::<$ty, _>(y) {
+                            let a_stride = blas_stride(&a, layout);
+                            let cblas_layout = layout.to_cblas_layout();
+
+                            // Low addr in memory pointers required for x, y
+                            unsafe {
+                                let x_offset = offset_from_low_addr_ptr_to_logical_ptr(&x.dim, &x.strides);
+                                let x_ptr = x.ptr.as_ptr().sub(x_offset);
+                                let y_offset = offset_from_low_addr_ptr_to_logical_ptr(&y.dim, &y.strides);
+                                let y_ptr = y.ptr.as_ptr().sub(y_offset);
+
+                                blas_sys::$ger(
+                                    cblas_layout,
+                                    m as blas_index,            // m, rows of a
+                                    n as blas_index,            // n, cols of a
+                                    $alpha,                     // alpha
+                                    x_ptr as *const _,          // x
+                                    x.strides()[0] as blas_index,
+                                    y_ptr as *const _,          // y
+                                    y.strides()[0] as blas_index,
+                                    a.ptr.as_ptr() as *mut _,   // a
+                                    a_stride,                   // lda
+                                );
+                            }
+                            return;
+                        }
+                    }
+                }
+            };
+        }
+        #[cfg(feature = "blas")]
+        ger!(f32, cblas_sger, cast_as(&alpha));
+        #[cfg(feature = "blas")]
+        ger!(f64, cblas_dger, cast_as(&alpha));
+        #[cfg(feature = "blas")]
+        ger!(c32, cblas_cgeru, &alpha as *const A as *const _);
+        #[cfg(feature = "blas")]
+        ger!(c64, cblas_zgeru, &alpha as *const A as *const _);
+
+        /* general */
+
+        Zip::from(a.rows_mut()).and(x).for_each(|row, &xi| {
+            let axi = alpha * xi;
+            Zip::from(row).and(y).for_each(|elt, &yj| {
+                *elt = *elt + axi * yj;
+            });
+        });
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn ger_shape_error(m: usize, n: usize, m2: usize, n2: usize) -> !
+{
+    panic!("ndarray: inputs of length {} and {} are not compatible for the rank-1 update of a {} × {} matrix",
+           m2, n2, m, n);
+}
+
 /// Kronecker product of 2D matrices.
 ///
 /// The kronecker product of a LxN matrix A and a MxR matrix B is a (L*M)x(N*R)
@@ -749,6 +1181,47 @@ where
     unsafe { out.assume_init() }
 }
 
+/// Block diagonal matrix of 2D matrices, also known as their direct sum.
+///
+/// The blocks are placed along the diagonal in order, each starting at the row and column
+/// after the end of the previous one, and the rest of the matrix is zero. The blocks may have
+/// any shape, so the result has as many rows as the blocks have rows in total, and as many
+/// columns as they have columns in total. An empty list of blocks gives a 0 × 0 matrix.
+///
+/// This is `scipy.linalg.block_diag`.
+///
+/// **Panics** if the number of elements in the result would overflow `isize`.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::block_diag;
+///
+/// let a = array![[1, 2], [3, 4]];
+/// let b = array![[5, 6, 7]];
+/// assert_eq!(
+///     block_diag(&[a.view(), b.view()]),
+///     array![[1, 2, 0, 0, 0],
+///            [3, 4, 0, 0, 0],
+///            [0, 0, 5, 6, 7]]
+/// );
+/// ```
+#[track_caller]
+pub fn block_diag<A>(blocks: &[ArrayView2<'_, A>]) -> Array2<A>
+where A: Clone + Zero
+{
+    let nrows = blocks.iter().map(|block| block.nrows()).sum();
+    let ncols = blocks.iter().map(|block| block.ncols()).sum();
+    let mut out = Array2::zeros((nrows, ncols));
+    let (mut row, mut col) = (0, 0);
+    for block in blocks {
+        let (m, n) = block.dim();
+        out.slice_mut(s![row..row + m, col..col + n]).assign(block);
+        row += m;
+        col += n;
+    }
+    out
+}
+
 #[inline(always)]
 /// Return `true` if `A` and `B` are the same type
 fn same_type<A: 'static, B: 'static>() -> bool
@@ -0,0 +1,145 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+use std::ops::{Deref, DerefMut, Mul};
+
+use crate::imp_prelude::*;
+use crate::{LinalgScalar, OwnedRepr, RawDataClone};
+
+/// A two-dimensional array for which `*` is matrix multiplication.
+///
+/// `*` on arrays multiplies elementwise; wrapping both operands in `Matrix`
+/// (usually with [`mat`]) makes it compute the matrix product with
+/// [`dot`](ArrayBase::dot) instead. The product is again a `Matrix`, so
+/// products can be chained.
+///
+/// `Matrix` dereferences to the wrapped array for all other operations.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::mat;
+///
+/// let a = array![[1., 2.],
+///                [0., 1.]];
+/// let b = array![[1., 2.],
+///                [2., 3.]];
+///
+/// assert_eq!(*(mat(&a) * mat(&b)), a.dot(&b));
+/// assert_eq!(*(mat(&a) * mat(&b) * mat(&a)), a.dot(&b).dot(&a));
+///
+/// // elementwise multiplication of the arrays themselves is unchanged
+/// assert_eq!(&a * &b, array![[1., 4.], [0., 3.]]);
+/// ```
+pub struct Matrix<S>(pub ArrayBase<S, Ix2>)
+where S: RawData;
+
+/// Wrap a view of `a` in a [`Matrix`], so that `*` is matrix multiplication.
+///
+/// ```
+/// use ndarray::array;
+/// use ndarray::linalg::mat;
+///
+/// let a = array![[1, 2], [3, 4]];
+/// let v = array![[1], [1]];
+/// assert_eq!(mat(&a) * mat(&v), mat(&array![[3], [7]]));
+/// ```
+pub fn mat<A, S>(a: &ArrayBase<S, Ix2>) -> Matrix<ViewRepr<&'_ A>>
+where S: Data<Elem = A>
+{
+    Matrix(a.view())
+}
+
+impl<S> Matrix<S>
+where S: RawData
+{
+    /// Return the wrapped array.
+    pub fn into_inner(self) -> ArrayBase<S, Ix2>
+    {
+        self.0
+    }
+}
+
+impl<S> From<ArrayBase<S, Ix2>> for Matrix<S>
+where S: RawData
+{
+    fn from(a: ArrayBase<S, Ix2>) -> Self
+    {
+        Matrix(a)
+    }
+}
+
+impl<S> Deref for Matrix<S>
+where S: RawData
+{
+    type Target = ArrayBase<S, Ix2>;
+
+    fn deref(&self) -> &Self::Target
+    {
+        &self.0
+    }
+}
+
+impl<S> DerefMut for Matrix<S>
+where S: RawData
+{
+    fn deref_mut(&mut self) -> &mut Self::Target
+    {
+        &mut self.0
+    }
+}
+
+/// Perform matrix multiplication with [`dot`](ArrayBase::dot).
+///
+/// **Panics** if the shapes are incompatible.
+impl<A, S, S2> Mul<Matrix<S2>> for Matrix<S>
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    type Output = Matrix<OwnedRepr<A>>;
+
+    #[track_caller]
+    fn mul(self, rhs: Matrix<S2>) -> Self::Output
+    {
+        Matrix(self.0.dot(&rhs.0))
+    }
+}
+
+impl<A, S, S2> PartialEq<Matrix<S2>> for Matrix<S>
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: PartialEq,
+{
+    fn eq(&self, rhs: &Matrix<S2>) -> bool
+    {
+        self.0 == rhs.0
+    }
+}
+
+impl<S> Clone for Matrix<S>
+where S: RawDataClone
+{
+    fn clone(&self) -> Self
+    {
+        Matrix(self.0.clone())
+    }
+}
+
+impl<A, S> fmt::Debug for Matrix<S>
+where
+    S: Data<Elem = A>,
+    A: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_tuple("Matrix").field(&self.0).finish()
+    }
+}
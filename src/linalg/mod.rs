@@ -8,9 +8,12 @@
 
 //! Linear algebra.
 
+pub use self::impl_linalg::block_diag;
 pub use self::impl_linalg::general_mat_mul;
 pub use self::impl_linalg::general_mat_vec_mul;
 pub use self::impl_linalg::kron;
 pub use self::impl_linalg::Dot;
+pub use self::matrix::{mat, Matrix};
 
 mod impl_linalg;
+mod matrix;
@@ -0,0 +1,148 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::{max, min};
+
+use crate::imp_prelude::*;
+use crate::{LinalgScalar, Zip};
+
+/// Output length of a convolution or correlation, see
+/// [`convolve_axis`](ArrayBase::convolve_axis).
+///
+/// With `n` the length of the input along the axis and `m` the length of the
+/// kernel, the modes match those of `numpy.convolve`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConvMode
+{
+    /// Every point where the input and the kernel overlap at all; the output
+    /// has length `n + m - 1`. The input is treated as zero outside its bounds.
+    Full,
+    /// The center part of the `Full` output, with length `max(n, m)`.
+    Same,
+    /// Only the points where the input and the kernel overlap completely; the
+    /// output has length `max(n, m) - min(n, m) + 1`.
+    Valid,
+}
+
+/// # Convolution
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Convolve each lane along `axis` with `kernel`.
+    ///
+    /// For one lane `a` of length `n` and a kernel `v` of length `m`, the
+    /// `Full` result is `c[k] = Σ a[i] * v[k - i]` for `k` in `0..n + m - 1`,
+    /// summing over the indices `i` where both `a` and `v` are in bounds.
+    /// `mode` selects which part of it is returned, see [`ConvMode`]; the
+    /// result is the same as that of `numpy.convolve`.
+    ///
+    /// The other axes keep their lengths.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if its length or the length of
+    /// `kernel` is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, ConvMode};
+    ///
+    /// let a = array![[1., 2., 3.], [4., 5., 6.]];
+    /// let kernel = array![0., 1., 0.5];
+    ///
+    /// assert_eq!(a.convolve_axis(Axis(1), kernel.view(), ConvMode::Full),
+    ///            array![[0., 1., 2.5, 4., 1.5], [0., 4., 7., 8.5, 3.]]);
+    /// assert_eq!(a.convolve_axis(Axis(1), kernel.view(), ConvMode::Same),
+    ///            array![[1., 2.5, 4.], [4., 7., 8.5]]);
+    /// assert_eq!(a.convolve_axis(Axis(1), kernel.view(), ConvMode::Valid),
+    ///            array![[2.5], [7.]]);
+    /// ```
+    #[track_caller]
+    pub fn convolve_axis(&self, axis: Axis, kernel: ArrayView1<'_, A>, mode: ConvMode) -> Array<A, D>
+    where A: LinalgScalar
+    {
+        let n = self.len_of(axis);
+        let m = kernel.len();
+        let same_offset = (min(n, m).saturating_sub(1)) / 2;
+        self.convolve_axis_impl(axis, kernel, mode, same_offset)
+    }
+
+    /// Compute the cross-correlation of each lane along `axis` with `kernel`.
+    ///
+    /// This is like [`convolve_axis`](Self::convolve_axis) but without
+    /// reversing the kernel: for one lane `a` the `Full` result is
+    /// `c[k] = Σ a[i + k - (m - 1)] * v[i]`, summing over the indices where
+    /// both are in bounds. `mode` selects which part of it is returned, see
+    /// [`ConvMode`]; the result is the same as that of `numpy.correlate` (for
+    /// real numbers, since the kernel is not conjugated).
+    ///
+    /// The other axes keep their lengths.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if its length or the length of
+    /// `kernel` is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, ConvMode};
+    ///
+    /// let a = array![1., 2., 3.];
+    /// let kernel = array![0., 1., 0.5];
+    ///
+    /// assert_eq!(a.correlate_axis(Axis(0), kernel.view(), ConvMode::Full),
+    ///            array![0.5, 2., 3.5, 3., 0.]);
+    /// assert_eq!(a.correlate_axis(Axis(0), kernel.view(), ConvMode::Same),
+    ///            array![2., 3.5, 3.]);
+    /// assert_eq!(a.correlate_axis(Axis(0), kernel.view(), ConvMode::Valid),
+    ///            array![3.5]);
+    /// ```
+    #[track_caller]
+    pub fn correlate_axis(&self, axis: Axis, kernel: ArrayView1<'_, A>, mode: ConvMode) -> Array<A, D>
+    where A: LinalgScalar
+    {
+        let n = self.len_of(axis);
+        let m = kernel.len();
+        // Correlating is convolving with the reversed kernel, except that when the
+        // kernel is the longer one, numpy centers `Same` on the other side.
+        let same_offset = if m > n { n / 2 } else { (m.saturating_sub(1)) / 2 };
+        self.convolve_axis_impl(axis, kernel.slice(s![..;-1]), mode, same_offset)
+    }
+
+    #[track_caller]
+    fn convolve_axis_impl(
+        &self, axis: Axis, kernel: ArrayView1<'_, A>, mode: ConvMode, same_offset: usize,
+    ) -> Array<A, D>
+    where A: LinalgScalar
+    {
+        let n = self.len_of(axis);
+        let m = kernel.len();
+        ndassert!(n > 0, "ndarray: can not convolve along axis {} of length zero", axis.index());
+        assert!(m > 0, "ndarray: kernel must not be empty");
+
+        // Output length and the index of its first element in the `Full` result
+        let (len, offset) = match mode {
+            ConvMode::Full => (n + m - 1, 0),
+            ConvMode::Same => (max(n, m), same_offset),
+            ConvMode::Valid => (max(n, m) - min(n, m) + 1, min(n, m) - 1),
+        };
+
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = len;
+        let mut result = Array::zeros(dim);
+        Zip::from(result.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .for_each(|mut out, lane| {
+                for (t, elt) in out.iter_mut().enumerate() {
+                    let k = t + offset;
+                    let mut acc = A::zero();
+                    for j in k.saturating_sub(n - 1)..=min(k, m - 1) {
+                        acc = acc + lane[k - j] * kernel[j];
+                    }
+                    *elt = acc;
+                }
+            });
+        result
+    }
+}
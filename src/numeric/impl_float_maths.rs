@@ -4,6 +4,7 @@
 use num_traits::Float;
 
 use crate::imp_prelude::*;
+use crate::Zip;
 
 #[cfg(feature = "std")]
 macro_rules! boolean_ops {
@@ -18,12 +19,12 @@ macro_rules! boolean_ops {
         $(#[$meta2])*
         #[must_use = "method returns a new boolean value and does not mutate the original value"]
         pub fn $all(&self) -> bool {
-            $crate::Zip::from(self).all(|&elt| !elt.$func())
+            $crate::Zip::from(self).all(|&elt| elt.$func())
         }
         $(#[$meta3])*
         #[must_use = "method returns a new boolean value and does not mutate the original value"]
         pub fn $any(&self) -> bool {
-            !self.$all()
+            !$crate::Zip::from(self).all(|&elt| !elt.$func())
         }
     };
 }
@@ -76,6 +77,14 @@ where
         /// Return `true` if any element is infinity.
         fn is_any_infinite
     }
+    boolean_ops! {
+        /// If the number is neither infinite nor `NaN`, then `true` is returned for each element.
+        fn is_finite
+        /// Return `true` if all elements are neither infinite nor `NaN`.
+        fn is_all_finite
+        /// Return `true` if any element is neither infinite nor `NaN`.
+        fn is_any_finite
+    }
     unary_ops! {
         /// The largest integer less than or equal to each element.
         fn floor
@@ -167,4 +176,95 @@ where
         assert!(min <= max, "min must be less than or equal to max");
         self.mapv(|a| num_traits::clamp(a, min.clone(), max.clone()))
     }
+
+    /// Limit the values for each element like [`.clamp()`](Self::clamp), and also return the
+    /// number of elements that were changed.
+    ///
+    /// An element is counted if it is less than `min` or greater than `max`. Elements that
+    /// can not be compared to the bounds, like `NaN`, are neither changed nor counted.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![-3., 0.5, 2., 7.];
+    /// assert_eq!(a.clip_counting(0., 1.), (array![0., 0.5, 1., 1.], 3));
+    ///
+    /// let (clipped, count) = array![f64::NAN, 0.5].clip_counting(0., 1.);
+    /// assert!(clipped[0].is_nan());
+    /// assert_eq!(count, 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `!(min <= max)`.
+    pub fn clip_counting(&self, min: A, max: A) -> (Array<A, D>, usize)
+    {
+        assert!(min <= max, "min must be less than or equal to max");
+        let mut count = 0;
+        let clipped = self.mapv(|a| {
+            if a < min {
+                count += 1;
+                min.clone()
+            } else if a > max {
+                count += 1;
+                max.clone()
+            } else {
+                a
+            }
+        });
+        (clipped, count)
+    }
+
+    /// Limit the values for each element in place, with bounds that vary along the lanes of
+    /// `axis`.
+    ///
+    /// `mins` and `maxs` have the shape of `self` with `axis` removed, and the element at
+    /// index `i` along `axis` and `rest` on the other axes is clamped to
+    /// `mins[rest]..=maxs[rest]`; in other words, each subview
+    /// [`.index_axis(axis, i)`](Self::index_axis) is clamped elementwise to `mins` and `maxs`.
+    /// For a matrix of observations by features, `clamp_axis(Axis(0), ..)` clamps each feature
+    /// (column) to its own range.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let mut a = array![[-1., 5., 0.5],
+    ///                    [3., -2., 0.2],
+    ///                    [0.5, 0.5, 9.]];
+    /// a.clamp_axis(Axis(0), array![0., -1., 0.3].view(), array![1., 2., 0.6].view());
+    /// assert_eq!(a, array![[0., 2., 0.5],
+    ///                      [1., -1., 0.3],
+    ///                      [0.5, 0.5, 0.6]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is out of bounds, or if the shape of `mins` or `maxs` is not that of
+    /// `self` with `axis` removed. Panics in debug mode if `!(min <= max)` for any pair of
+    /// bounds.
+    #[track_caller]
+    pub fn clamp_axis(&mut self, axis: Axis, mins: ArrayView<'_, A, D::Smaller>, maxs: ArrayView<'_, A, D::Smaller>)
+    where
+        S: DataMut,
+        D: RemoveAxis,
+    {
+        let reduced = self.raw_dim().remove_axis(axis);
+        ndassert!(
+            mins.shape() == reduced.slice() && maxs.shape() == reduced.slice(),
+            "ndarray: clamp_axis: bounds of shape {:?} and {:?} do not match the reduced shape {:?}",
+            mins.shape(),
+            maxs.shape(),
+            reduced.slice()
+        );
+        debug_assert!(
+            Zip::from(&mins).and(&maxs).all(|min, max| min <= max),
+            "ndarray: clamp_axis: every min must be less than or equal to the corresponding max"
+        );
+        for mut subview in self.axis_iter_mut(axis) {
+            Zip::from(&mut subview)
+                .and(&mins)
+                .and(&maxs)
+                .for_each(|a, min, max| *a = num_traits::clamp(a.clone(), min.clone(), max.clone()));
+        }
+    }
 }
@@ -6,14 +6,18 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use num_traits::Float;
 use num_traits::One;
 use num_traits::{FromPrimitive, Zero};
+use std::cmp::Ordering;
 use std::ops::{Add, Div, Mul, Sub};
 
 use crate::imp_prelude::*;
 use crate::numeric_util;
+use crate::IntoDimension;
 use crate::Slice;
 
 /// # Numerical Methods for Arrays
@@ -245,6 +249,32 @@ where
         }
     }
 
+    /// Return sum along `axis`, keeping `axis` with length 1.
+    ///
+    /// This is [`.sum_axis(axis)`](Self::sum_axis) with `axis` inserted again, like
+    /// `keepdims=True` in NumPy, so that the result has the same number of dimensions as `self`
+    /// and broadcasts against it.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 6.]];
+    /// let row_sums = a.sum_axis_keep(Axis(1));
+    /// assert_eq!(row_sums, array![[6.], [15.]]);
+    /// assert_eq!(&a / &row_sums, array![[1. / 6., 2. / 6., 3. / 6.], [4. / 15., 5. / 15., 6. / 15.]]);
+    /// ```
+    #[track_caller]
+    pub fn sum_axis_keep(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + Zero + Add<Output = A>,
+        D: RemoveAxis,
+    {
+        keep_axis(self.sum_axis(axis), axis)
+    }
+
     /// Return product along `axis`.
     ///
     /// The product of an empty array is 1.
@@ -317,6 +347,104 @@ where
         }
     }
 
+    /// Return mean along `axis`, keeping `axis` with length 1.
+    ///
+    /// This is [`.mean_axis(axis)`](Self::mean_axis) with `axis` inserted again, like
+    /// `keepdims=True` in NumPy, so that the result has the same number of dimensions as `self`
+    /// and broadcasts against it.
+    ///
+    /// Return `None` if the length of the axis is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()`
+    /// fails for the axis length.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 3.],
+    ///                [4., 5., 9.]];
+    /// let centered = &a - &a.mean_axis_keep(Axis(1)).unwrap();
+    /// assert_eq!(centered, array![[-1., 0., 1.], [-2., -1., 3.]]);
+    /// ```
+    #[track_caller]
+    pub fn mean_axis_keep(&self, axis: Axis) -> Option<Array<A, D>>
+    where
+        A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>,
+        D: RemoveAxis,
+    {
+        self.mean_axis(axis).map(|mean| keep_axis(mean, axis))
+    }
+
+    /// Return the sum along all of `axes`.
+    ///
+    /// The result has the shape of `self` with all of `axes` removed, in the order of the
+    /// remaining axes, and it is the same as removing the axes one at a time with
+    /// [`.sum_axis()`](Self::sum_axis), without having to adjust the indices of the later axes
+    /// for the removed ones. The order of `axes` does not matter. An empty list of axes gives
+    /// a copy of `self`.
+    ///
+    /// **Panics** if any of `axes` is out of bounds, or if an axis is listed more than once.
+    ///
+    /// ```
+    /// use ndarray::{array, Array, Axis};
+    ///
+    /// let a = Array::from_iter(0..24).into_shape_with_order((2, 3, 4)).unwrap();
+    /// let s = a.sum_axes(&[Axis(2), Axis(0)]);
+    /// assert_eq!(s.shape(), &[3]);
+    /// assert_eq!(s, array![60, 92, 124].into_dyn());
+    /// assert_eq!(a.sum_axes(&[Axis(0), Axis(1), Axis(2)])[[]], a.sum());
+    /// ```
+    #[track_caller]
+    pub fn sum_axes(&self, axes: &[Axis]) -> Array<A, IxDyn>
+    where A: Clone + Zero + Add<Output = A>
+    {
+        let axes = reduced_axes(self.ndim(), axes);
+        let view = self.view().into_dyn();
+        match axes.split_first() {
+            None => view.to_owned(),
+            Some((&first, rest)) => {
+                let mut sum = view.sum_axis(Axis(first));
+                for &axis in rest {
+                    sum = sum.sum_axis(Axis(axis));
+                }
+                sum
+            }
+        }
+    }
+
+    /// Return the mean along all of `axes`.
+    ///
+    /// The result has the shape of `self` with all of `axes` removed, see
+    /// [`.sum_axes()`](Self::sum_axes); each of its elements is the mean of the elements of
+    /// `self` that are reduced into it.
+    ///
+    /// Return `None` if the length of any of `axes` is zero.
+    ///
+    /// **Panics** if any of `axes` is out of bounds, if an axis is listed more than once, or
+    /// if `A::from_usize()` fails for the number of elements reduced into each element of the
+    /// result.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[[1., 2.], [3., 4.]],
+    ///                [[5., 6.], [7., 8.]]];
+    /// assert_eq!(a.mean_axes(&[Axis(0), Axis(2)]).unwrap(), array![3.5, 5.5].into_dyn());
+    /// ```
+    #[track_caller]
+    pub fn mean_axes(&self, axes: &[Axis]) -> Option<Array<A, IxDyn>>
+    where A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>
+    {
+        let sum = self.sum_axes(axes);
+        let count: usize = axes.iter().map(|&axis| self.len_of(axis)).product();
+        if count == 0 {
+            None
+        } else {
+            let count = A::from_usize(count).expect("Converting the number of elements to `A` must not fail.");
+            Some(sum / aview0(&count))
+        }
+    }
+
     /// Return variance along `axis`.
     ///
     /// The variance is computed using the [Welford one-pass
@@ -493,4 +621,629 @@ where
         }
         inp
     }
+
+    /// Return the gradient along `axis` of the function sampled by the array, for samples that
+    /// are `spacing` apart along `axis`.
+    ///
+    /// The gradient is computed with second order central differences in the interior,
+    /// `(arr[i+1] - arr[i-1]) / (2 * spacing)`, and first order one-sided differences at the
+    /// two ends, like `numpy.gradient`. The result has the same shape as `self`.
+    ///
+    /// **Panics** if `axis` is out of bounds or if its length is less than 2.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let f = array![1., 2., 4., 7., 11.];
+    /// assert_eq!(f.gradient_axis(Axis(0), 1.), array![1., 1.5, 2.5, 3.5, 4.]);
+    /// assert_eq!(f.gradient_axis(Axis(0), 0.5), array![2., 3., 5., 7., 8.]);
+    /// ```
+    #[track_caller]
+    pub fn gradient_axis(&self, axis: Axis, spacing: A) -> Array<A, D>
+    where A: Clone + Zero + Add<Output = A> + Sub<Output = A> + Div<Output = A>
+    {
+        let n = self.len_of(axis);
+        ndassert!(
+            n >= 2,
+            "ndarray: gradient_axis: axis {} has length {}, but the length must be at least 2",
+            axis.index(),
+            n
+        );
+        let mut out = Array::zeros(self.raw_dim());
+        let double = spacing.clone() + spacing.clone();
+        azip!((o in out.slice_axis_mut(axis, Slice::new(1, Some(-1), 1)),
+               lo in self.slice_axis(axis, Slice::from(..-2)),
+               hi in self.slice_axis(axis, Slice::from(2..)))
+            *o = (hi.clone() - lo.clone()) / double.clone());
+        // one-sided differences at the ends
+        for (i, lo, hi) in [(0, 0, 1), (n - 1, n - 2, n - 1)] {
+            azip!((o in out.slice_axis_mut(axis, Slice::from(i..i + 1)),
+                   lo in self.slice_axis(axis, Slice::from(lo..lo + 1)),
+                   hi in self.slice_axis(axis, Slice::from(hi..hi + 1)))
+                *o = (hi.clone() - lo.clone()) / spacing.clone());
+        }
+        out
+    }
+
+    /// Return the gradient of the function sampled by the array along each of its axes, for
+    /// samples that are `spacing[i]` apart along axis `i`.
+    ///
+    /// Element `i` of the result is [`.gradient_axis(Axis(i), spacing[i])`](Self::gradient_axis),
+    /// like `numpy.gradient` without an axis argument; for a scalar field sampled
+    /// on a grid, the results are the components of its gradient vector field.
+    ///
+    /// **Panics** if the length of `spacing` is not `self.ndim()`, or if the length of any axis
+    /// is less than 2.
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    ///
+    /// // f(y, x) = x + 10 y, sampled with a step of 0.5 along y and 1 along x
+    /// let f = Array2::from_shape_fn((3, 4), |(i, j)| j as f64 + 10. * (0.5 * i as f64));
+    /// let grad = f.gradient(&[0.5, 1.]);
+    /// assert_eq!(grad.len(), 2);
+    /// assert_eq!(grad[0], Array2::from_elem((3, 4), 10.));
+    /// assert_eq!(grad[1], Array2::from_elem((3, 4), 1.));
+    /// ```
+    #[track_caller]
+    pub fn gradient(&self, spacing: &[A]) -> Vec<Array<A, D>>
+    where A: Clone + Zero + Add<Output = A> + Sub<Output = A> + Div<Output = A>
+    {
+        ndassert!(
+            spacing.len() == self.ndim(),
+            "ndarray: gradient: {} spacings given for an array of {} dimensions",
+            spacing.len(),
+            self.ndim()
+        );
+        spacing
+            .iter()
+            .enumerate()
+            .map(|(i, h)| self.gradient_axis(Axis(i), h.clone()))
+            .collect()
+    }
+
+    /// Return the index of the bin that each element falls into.
+    ///
+    /// `bins` holds the bin edges, which must be sorted in increasing order. With
+    /// `right` false, the index for an element `x` is the `i` such that
+    /// `bins[i - 1] <= x < bins[i]`: bins include their left edge. With `right`
+    /// true, it is the `i` such that `bins[i - 1] < x <= bins[i]`: bins include
+    /// their right edge. Elements below the first edge get index 0 and elements
+    /// above the last edge get index `bins.len()`, so there are `bins.len() + 1`
+    /// possible indices. This matches `numpy.digitize` for increasing bins.
+    ///
+    /// Put differently, the index is the number of edges that are `<= x` (or
+    /// `< x` with `right` true); in particular, incomparable elements such as NaN
+    /// get index 0. Each element is found by binary search, and the result has
+    /// the same shape as `self`.
+    ///
+    /// If `bins` is not sorted, the result is unspecified but memory safe.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let x = array![[0.2, 6.4, 3.0], [1.6, 1.0, -1.]];
+    /// let bins = array![0.0, 1.0, 2.5, 4.0, 10.0];
+    /// assert_eq!(x.digitize(bins.view(), false), array![[1, 4, 3], [2, 2, 0]]);
+    /// assert_eq!(x.digitize(bins.view(), true), array![[1, 4, 3], [2, 1, 0]]);
+    /// ```
+    pub fn digitize(&self, bins: ArrayView1<'_, A>, right: bool) -> Array<usize, D>
+    where A: PartialOrd
+    {
+        self.map(|x| {
+            // binary search for the number of edges that are <= x (or < x)
+            let (mut lo, mut hi) = (0, bins.len());
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                let edge = &bins[mid];
+                if edge < x || (!right && edge == x) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        })
+    }
+
+    /// Return the indices of the nonzero elements of the array, like `numpy.argwhere`.
+    ///
+    /// The result has one row per nonzero element, holding its index, and one column per axis
+    /// of `self`. The rows are in logical order, the order of
+    /// [`.indexed_iter()`](Self::indexed_iter). If all elements are zero, the result has shape
+    /// `(0, self.ndim())`.
+    ///
+    /// For one-dimensional arrays, see also [`.nonzero_1d()`](ArrayBase::nonzero_1d).
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[0, 3, 0],
+    ///                [4, 0, 5]];
+    /// assert_eq!(a.nonzero(), array![[0, 1], [1, 0], [1, 2]]);
+    /// assert_eq!(a.t().nonzero(), array![[0, 1], [1, 0], [2, 1]]);
+    /// assert_eq!(a.mapv(|_| 0).nonzero().shape(), &[0, 2]);
+    /// ```
+    pub fn nonzero(&self) -> Array2<usize>
+    where A: Zero
+    {
+        let mut indices = Vec::new();
+        let mut count = 0;
+        for (index, elt) in self.indexed_iter() {
+            if !elt.is_zero() {
+                indices.extend_from_slice(index.into_dimension().slice());
+                count += 1;
+            }
+        }
+        Array2::from_shape_vec((count, self.ndim()), indices).unwrap()
+    }
+
+    /// Return the smallest and the largest element of the array, found in a single pass.
+    ///
+    /// Return `None` if the array is empty. If some elements can not be compared, like NaN,
+    /// the result is unspecified, but it is a pair of elements of the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[3, -1, 4], [1, 5, 9]];
+    /// assert_eq!(a.minmax(), Some((-1, 9)));
+    /// assert_eq!(a.slice(ndarray::s![.., ..0]).minmax(), None);
+    /// ```
+    pub fn minmax(&self) -> Option<(A, A)>
+    where A: Clone + PartialOrd
+    {
+        self.fold(None, |acc: Option<(&A, &A)>, x| match acc {
+            None => Some((x, x)),
+            Some((min, max)) => Some((if x < min { x } else { min }, if x > max { x } else { max })),
+        })
+        .map(|(min, max)| (min.clone(), max.clone()))
+    }
+
+    /// Return the smallest and the largest elements along `axis`, found in a single pass.
+    ///
+    /// The result is a pair of arrays, of the smallest and of the largest elements of each lane
+    /// along `axis`; they have the shape of `self` with `axis` removed. If some elements can not
+    /// be compared, like NaN, the result for their lanes is unspecified, but it is a pair of
+    /// elements of the lane.
+    ///
+    /// **Panics** if `axis` is out of bounds or if its length is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, -1, 4], [1, 5, 9]];
+    /// let (min, max) = a.minmax_axis(Axis(0));
+    /// assert_eq!(min, array![1, -1, 4]);
+    /// assert_eq!(max, array![3, 5, 9]);
+    /// assert_eq!(a.minmax_axis(Axis(1)), (array![-1, 1], array![4, 9]));
+    /// ```
+    #[track_caller]
+    pub fn minmax_axis(&self, axis: Axis) -> (Array<A, D::Smaller>, Array<A, D::Smaller>)
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        ndassert!(
+            self.len_of(axis) > 0,
+            "ndarray: minmax_axis: axis {} has length zero",
+            axis.index()
+        );
+        let mut subviews = self.axis_iter(axis);
+        let first = subviews.next().unwrap();
+        let mut min = first.to_owned();
+        let mut max = first.to_owned();
+        for subview in subviews {
+            crate::Zip::from(&mut min)
+                .and(&mut max)
+                .and(&subview)
+                .for_each(|min, max, x| {
+                    if x < min {
+                        *min = x.clone();
+                    } else if x > max {
+                        *max = x.clone();
+                    }
+                });
+        }
+        (min, max)
+    }
+
+    /// Return the smallest and the largest elements along `axis`, keeping `axis` with length 1.
+    ///
+    /// This is [`.minmax_axis(axis)`](Self::minmax_axis) with `axis` inserted again in both
+    /// results, like `keepdims=True` in NumPy, so that they have the same number of dimensions
+    /// as `self` and broadcast against it.
+    ///
+    /// **Panics** if `axis` is out of bounds or if its length is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, -1, 4], [1, 5, 9]];
+    /// let (min, max) = a.minmax_axis_keep(Axis(1));
+    /// assert_eq!(min, array![[-1], [1]]);
+    /// assert_eq!(&a - &min, array![[4, 0, 5], [0, 4, 8]]);
+    /// assert_eq!(max, array![[4], [9]]);
+    /// ```
+    #[track_caller]
+    pub fn minmax_axis_keep(&self, axis: Axis) -> (Array<A, D>, Array<A, D>)
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        let (min, max) = self.minmax_axis(axis);
+        (keep_axis(min, axis), keep_axis(max, axis))
+    }
+
+    /// Return the cumulative maximum along `axis`.
+    ///
+    /// The element at position `i` along `axis` is the largest of the elements at positions
+    /// `0..=i` of the same lane, so that each lane of the result is non-decreasing. The result
+    /// has the same shape as `self`.
+    ///
+    /// Elements that can not be compared with themselves, like NaN, propagate: from the first
+    /// NaN of a lane on, the rest of the lane is NaN too, like `numpy.maximum.accumulate`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let prices = array![[3., 5., 4., 6., 2.],
+    ///                     [9., 8., 9., 7., 10.]];
+    /// let peak = prices.cummax_axis(Axis(1));
+    /// assert_eq!(peak, array![[3., 5., 5., 6., 6.], [9., 9., 9., 9., 10.]]);
+    /// // drawdown from the running peak
+    /// assert_eq!(&peak - &prices, array![[0., 0., 1., 0., 4.], [0., 1., 0., 2., 0.]]);
+    ///
+    /// let x: ndarray::Array1<f64> = array![1., f64::NAN, 3.].cummax_axis(Axis(0));
+    /// assert!(x[0] == 1. && x[1].is_nan() && x[2].is_nan());
+    /// ```
+    #[track_caller]
+    pub fn cummax_axis(&self, axis: Axis) -> Array<A, D>
+    where A: Clone + PartialOrd
+    {
+        self.cumulative_extremum(axis, Ordering::Greater)
+    }
+
+    /// Return the cumulative minimum along `axis`.
+    ///
+    /// The element at position `i` along `axis` is the smallest of the elements at positions
+    /// `0..=i` of the same lane, so that each lane of the result is non-increasing. The result
+    /// has the same shape as `self`.
+    ///
+    /// NaN propagates in the same way as in [`.cummax_axis()`](Self::cummax_axis).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[3, 5], [1, 6], [2, 4]];
+    /// assert_eq!(a.cummin_axis(Axis(0)), array![[3, 5], [1, 5], [1, 4]]);
+    /// assert_eq!(a.cummin_axis(Axis(1)), array![[3, 3], [1, 1], [2, 2]]);
+    /// ```
+    #[track_caller]
+    pub fn cummin_axis(&self, axis: Axis) -> Array<A, D>
+    where A: Clone + PartialOrd
+    {
+        self.cumulative_extremum(axis, Ordering::Less)
+    }
+
+    /// Running extremum along `axis`, where `ord` is the ordering with which the running value
+    /// replaces the next element (`Greater` for the maximum).
+    #[track_caller]
+    fn cumulative_extremum(&self, axis: Axis, ord: Ordering) -> Array<A, D>
+    where A: Clone + PartialOrd
+    {
+        let mut res = self.to_owned();
+        res.accumulate_axis_inplace(axis, |prev, curr| {
+            // an incomparable `prev` (NaN) replaces everything after it; an incomparable `curr`
+            // is left as is
+            let curr_ref = &*curr;
+            if curr_ref.partial_cmp(curr_ref).is_some() && prev.partial_cmp(curr_ref).map_or(true, |o| o == ord) {
+                *curr = prev.clone();
+            }
+        });
+        res
+    }
+
+    /// Rescale each lane along `axis` linearly, so that its smallest element becomes `low` and
+    /// its largest element becomes `high`, also known as min-max normalization.
+    ///
+    /// The smallest and largest elements are those of
+    /// [`.minmax_axis(axis)`](Self::minmax_axis), and each element `x` of a lane with those
+    /// `min` and `max` is mapped to `low * (1 - t) + high * t` with
+    /// `t = (x - min) / (max - min)`, so that the extremes map to exactly `low` and `high`.
+    /// Elements of a lane whose elements are all equal are mapped to `low`. `low` may be
+    /// greater than `high`, which reverses the order of the elements.
+    ///
+    /// The result has the same shape as `self`. If a lane contains NaN, the result for that
+    /// lane is unspecified.
+    ///
+    /// **Panics** if `axis` is out of bounds or if its length is zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1., 2., 5.],
+    ///                [3., 3., 3.]];
+    /// assert_eq!(a.rescale_axis(Axis(1), 0., 1.), array![[0., 0.25, 1.], [0., 0., 0.]]);
+    /// assert_eq!(a.rescale_axis(Axis(0), -1., 1.), array![[-1., -1., 1.], [1., 1., -1.]]);
+    /// ```
+    #[track_caller]
+    #[cfg(feature = "std")]
+    pub fn rescale_axis(&self, axis: Axis, low: A, high: A) -> Array<A, D>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        let (min, max) = self.minmax_axis(axis);
+        crate::Zip::from(self)
+            .and_broadcast(&min.insert_axis(axis))
+            .and_broadcast(&max.insert_axis(axis))
+            .map_collect(|&x, &min, &max| {
+                let t = if max > min { (x - min) / (max - min) } else { A::zero() };
+                low * (A::one() - t) + high * t
+            })
+    }
+
+    /// Linearly interpolate each lane along `axis` at the coordinates `x`.
+    ///
+    /// Each lane along `axis` holds the values of a function at the increasing coordinates
+    /// `xp`, and is resampled at the coordinates `x` like `numpy.interp`. The result has the
+    /// same shape as `self`, except that its length along `axis` is `x.len()`.
+    ///
+    /// Coordinates outside of the range of `xp` are clamped to it, so before `xp[0]` the
+    /// result is the first element of the lane and after the last element of `xp` it is the
+    /// last element of the lane. If `xp` has repeated coordinates, the value at such a
+    /// coordinate is the last of them. A NaN coordinate gives NaN.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the length of `xp` is not that of `axis`, if
+    /// `xp` is not sorted in increasing order, or if `xp` is empty and `x` is not.
+    ///
+    /// ```
+    /// use ndarray::{array, aview1, Axis};
+    ///
+    /// // two channels sampled at 0, 1 and 3
+    /// let fp = array![[0., 10., 30.],
+    ///                 [5., 5., 1.]];
+    /// let xp = aview1(&[0., 1., 3.]);
+    /// let x = aview1(&[-1., 0.5, 2., 4.]);
+    /// assert_eq!(fp.interp_axis(Axis(1), x, xp),
+    ///            array![[0., 5., 20., 30.],
+    ///                   [5., 5., 3., 1.]]);
+    /// ```
+    #[track_caller]
+    #[cfg(feature = "std")]
+    pub fn interp_axis(&self, axis: Axis, x: ArrayView1<'_, A>, xp: ArrayView1<'_, A>) -> Array<A, D>
+    where A: Float
+    {
+        ndassert!(
+            xp.len() == self.len_of(axis),
+            "ndarray: interp_axis: xp has length {}, but the axis length is {}",
+            xp.len(),
+            self.len_of(axis)
+        );
+        ndassert!(
+            xp.windows(2).into_iter().all(|w| w[0] <= w[1]),
+            "ndarray: interp_axis: xp must be sorted in increasing order"
+        );
+        ndassert!(
+            !xp.is_empty() || x.is_empty(),
+            "ndarray: interp_axis: can not interpolate an axis of length zero"
+        );
+
+        // The elements of each lane to interpolate between, and the weight of the second one
+        let n = xp.len();
+        let weights: Vec<(usize, usize, A)> = x
+            .iter()
+            .map(|&x| {
+                if x.is_nan() {
+                    return (0, 0, x);
+                }
+                // binary search for the first index with xp[hi] > x
+                let (mut lo, mut hi) = (0, n);
+                while lo < hi {
+                    let mid = lo + (hi - lo) / 2;
+                    if xp[mid] <= x {
+                        lo = mid + 1;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                if hi == 0 {
+                    (0, 0, A::zero())
+                } else if hi == n {
+                    (n - 1, n - 1, A::zero())
+                } else {
+                    let lo = hi - 1;
+                    (lo, hi, (x - xp[lo]) / (xp[hi] - xp[lo]))
+                }
+            })
+            .collect();
+
+        let mut dim = self.raw_dim();
+        dim[axis.index()] = x.len();
+        let mut res = Array::zeros(dim);
+        crate::Zip::from(self.lanes(axis))
+            .and(res.lanes_mut(axis))
+            .for_each(|fp, mut lane| {
+                for (y, &(lo, hi, t)) in lane.iter_mut().zip(&weights) {
+                    *y = fp[lo] * (A::one() - t) + fp[hi] * t;
+                }
+            });
+        res
+    }
+
+    /// Return the index and magnitude of the largest elementwise absolute
+    /// difference between `self` and `other`.
+    ///
+    /// This is useful to find out where and by how much two arrays differ,
+    /// for example when a floating point comparison fails in a test. If
+    /// several elements have the same largest difference, the first one in
+    /// logical order is reported. A NaN difference counts as larger than any
+    /// other, so the first NaN difference is reported if there is one.
+    ///
+    /// Return `None` if the shapes of the arrays differ or if they are empty.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let a = array![[1., 2.], [3., 4.]];
+    /// let b = array![[1., 2.5], [2.9, 4.]];
+    /// assert_eq!(a.max_abs_diff(&b), Some(((0, 1), 0.5)));
+    /// assert_eq!(a.max_abs_diff(&a), Some(((0, 0), 0.)));
+    /// assert_eq!(a.max_abs_diff(&array![[1., 2.]]), None);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn max_abs_diff<S2>(&self, other: &ArrayBase<S2, D>) -> Option<(D::Pattern, A)>
+    where
+        A: Float,
+        S2: Data<Elem = A>,
+    {
+        if self.shape() != other.shape() {
+            return None;
+        }
+        let mut max: Option<(D::Pattern, A)> = None;
+        for ((index, &x), &y) in self.indexed_iter().zip(other) {
+            let diff = (x - y).abs();
+            match max {
+                Some((_, m)) if m.is_nan() || !(diff.is_nan() || diff > m) => {}
+                _ => max = Some((index, diff)),
+            }
+        }
+        max
+    }
+}
+
+/// # Numerical Methods for 2-D Arrays
+impl<A, S> ArrayBase<S, Ix2>
+where S: Data<Elem = A>
+{
+    /// Return the covariance matrix of the variables in the rows of `self`.
+    ///
+    /// Each row of `self` is a variable and each column is an observation, so that for *M*
+    /// variables and *N* observations `self` is *M* × *N* and the result is *M* × *M*. This is
+    /// the convention of `numpy.cov`. Element `[i, j]` of the result is the covariance of the
+    /// variables `i` and `j`:
+    ///
+    /// ```text
+    ///              1       N
+    /// cov(x, y) = ――――――――   ∑ (xₖ - x̅)(yₖ - y̅)
+    ///             N - ddof  k=1
+    /// ```
+    ///
+    /// As for [`.var()`](ArrayBase::var), `ddof` is the "delta degrees of freedom": use
+    /// `ddof = 1` for the sample covariance (the `numpy.cov` default) or `ddof = 0` for the
+    /// population covariance. The diagonal of the result holds the variances of the variables.
+    ///
+    /// The product of the centered data is computed with [`.dot()`](ArrayBase::dot); if `ddof`
+    /// is equal to the number of observations, for example for a single observation and
+    /// `ddof = 1`, the result is NaN.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the number of observations.
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // Two variables with three observations each
+    /// let x = array![[0., 1., 2.],
+    ///                [2., 1., 0.]];
+    /// assert_eq!(x.cov(1.), array![[1., -1.], [-1., 1.]]);
+    /// assert_eq!(x.cov(0.), array![[2. / 3., -2. / 3.], [-2. / 3., 2. / 3.]]);
+    /// ```
+    #[track_caller]
+    #[cfg(feature = "std")]
+    pub fn cov(&self, ddof: A) -> Array2<A>
+    where A: 'static + Float + FromPrimitive
+    {
+        let n = A::from_usize(self.ncols()).expect("Converting number of observations to `A` must not fail.");
+        assert!(
+            !(ddof < A::zero() || ddof > n),
+            "`ddof` must not be less than zero or greater than the number of observations",
+        );
+        let dof = n - ddof;
+        let mean = self.sum_axis(Axis(1)).mapv_into(|sum| sum / n);
+        let centered = self - &mean.insert_axis(Axis(1));
+        centered.dot(&centered.t()).mapv_into(|sum| sum / dof)
+    }
+
+    /// Return the matrix of Pearson correlation coefficients of the variables in the rows of
+    /// `self`, like `numpy.corrcoef`.
+    ///
+    /// Each row of `self` is a variable and each column is an observation, as for
+    /// [`.cov()`](ArrayBase::cov). Element `[i, j]` of the result is
+    /// `cov[i, j] / sqrt(cov[i, i] * cov[j, j])`, clipped to the interval `[-1, 1]` to
+    /// correct for rounding; `ddof` cancels out, so it is not a parameter.
+    ///
+    /// A variable with zero variance, which is the case for all variables if there is only a
+    /// single observation, gives NaN in its row and column of the result, and so does an
+    /// array with no observations.
+    ///
+    /// ```
+    /// use ndarray::{array, Array2};
+    ///
+    /// let x = array![[1., 2., 3., 4.],
+    ///                [2., 4., 6., 8.],
+    ///                [4., 3., 2., 1.]];
+    /// let expected = array![[1., 1., -1.],
+    ///                       [1., 1., -1.],
+    ///                       [-1., -1., 1.]];
+    /// assert!((x.corrcoef() - expected).iter().all(|d: &f64| d.abs() < 1e-12));
+    ///
+    /// let single: Array2<f64> = array![[1.], [2.]];
+    /// assert!(single.corrcoef().iter().all(|r| r.is_nan()));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn corrcoef(&self) -> Array2<A>
+    where A: 'static + Float + FromPrimitive
+    {
+        let cov = self.cov(A::zero());
+        let std = cov.diag().mapv(A::sqrt);
+        let mut corr = cov;
+        crate::Zip::indexed(&mut corr).for_each(|(i, j), r| {
+            *r = *r / (std[i] * std[j]);
+            // clip, but keep NaN
+            if *r > A::one() {
+                *r = A::one();
+            } else if *r < -A::one() {
+                *r = -A::one();
+            }
+        });
+        corr
+    }
+}
+
+/// Insert `axis` again into the result of a reduction along it
+fn keep_axis<A, E, D>(reduced: Array<A, E>, axis: Axis) -> Array<A, D>
+where
+    E: Dimension,
+    D: Dimension,
+{
+    reduced
+        .insert_axis(axis)
+        .into_dimensionality()
+        .expect("the reduced array with the axis inserted has the same dimension")
+}
+
+/// Check that `axes` are distinct and in bounds for `ndim` axes, and return them in decreasing
+/// order, so that they can be removed one at a time.
+#[track_caller]
+fn reduced_axes(ndim: usize, axes: &[Axis]) -> Vec<usize>
+{
+    let mut sorted: Vec<usize> = axes.iter().map(|axis| axis.index()).collect();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    if let Some(&max) = sorted.first() {
+        ndassert!(
+            max < ndim,
+            "ndarray: axis {} is out of bounds for array of dimension {}",
+            max,
+            ndim
+        );
+    }
+    for pair in sorted.windows(2) {
+        ndassert!(pair[0] != pair[1], "ndarray: axis {} is listed more than once", pair[0]);
+    }
+    sorted
 }
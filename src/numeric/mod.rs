@@ -1,3 +1,9 @@
 mod impl_numeric;
 
 mod impl_float_maths;
+
+mod convolve;
+pub use self::convolve::ConvMode;
+
+mod rank;
+pub use self::rank::RankMethod;
@@ -0,0 +1,123 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use std::cmp::Ordering;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+/// How to rank equal elements, see [`rank_axis`](ArrayBase::rank_axis).
+///
+/// The methods match those of the same name in `scipy.stats.rankdata`. For
+/// the lane `10 20 20 30` the ranks are:
+///
+/// ```text
+/// Average  1 2.5 2.5 4
+/// Min      1 2   2   4
+/// Max      1 3   3   4
+/// Dense    1 2   2   3
+/// Ordinal  1 2   3   4
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RankMethod
+{
+    /// Equal elements get the average of the ranks they span.
+    Average,
+    /// Equal elements get the lowest of the ranks they span.
+    Min,
+    /// Equal elements get the highest of the ranks they span.
+    Max,
+    /// Like `Min`, but the next larger element gets the next rank, so that
+    /// there are no gaps between ranks.
+    Dense,
+    /// Each element gets a distinct rank; equal elements are ranked in the
+    /// order they appear in the lane.
+    Ordinal,
+}
+
+/// # Ranking
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return the rank of each element within its lane along `axis`.
+    ///
+    /// The smallest element of a lane has rank 1 and the largest has rank
+    /// `n`, for a lane of length `n`; `method` selects the ranks of equal
+    /// elements, see [`RankMethod`]. The result has the same shape as `self`
+    /// and is the same as that of `scipy.stats.rankdata`.
+    ///
+    /// Elements that are not comparable with themselves, like NaN, rank after
+    /// all other elements and are equal to each other. Any other elements that
+    /// can not be compared are treated as equal; the ranks of such a lane are
+    /// unspecified and computing them may panic.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, RankMethod};
+    ///
+    /// let scores = array![[7., 3., 7., 1.],
+    ///                     [2., 2., 2., 9.]];
+    /// assert_eq!(scores.rank_axis(Axis(1), RankMethod::Average),
+    ///            array![[3.5, 2., 3.5, 1.], [2., 2., 2., 4.]]);
+    /// assert_eq!(scores.rank_axis(Axis(1), RankMethod::Dense),
+    ///            array![[3., 2., 3., 1.], [1., 1., 1., 2.]]);
+    /// ```
+    #[track_caller]
+    pub fn rank_axis(&self, axis: Axis, method: RankMethod) -> Array<f64, D>
+    where A: PartialOrd
+    {
+        let mut ranks = Array::zeros(self.raw_dim());
+        let mut order = Vec::with_capacity(self.len_of(axis));
+        Zip::from(self.lanes(axis))
+            .and(ranks.lanes_mut(axis))
+            .for_each(|lane, mut lane_ranks| {
+                order.clear();
+                order.extend(0..lane.len());
+                // stable, so that equal elements stay in order for `Ordinal`
+                order.sort_by(|&i, &j| cmp_nan_last(&lane[i], &lane[j]));
+
+                let mut dense = 0;
+                let mut start = 0;
+                while start < order.len() {
+                    let mut end = start + 1;
+                    while end < order.len() && cmp_nan_last(&lane[order[end]], &lane[order[start]]) == Ordering::Equal {
+                        end += 1;
+                    }
+                    dense += 1;
+                    // elements order[start..end] are equal and span ranks start + 1..=end
+                    for (k, &i) in order[start..end].iter().enumerate() {
+                        lane_ranks[i] = match method {
+                            RankMethod::Average => (start + 1 + end) as f64 / 2.,
+                            RankMethod::Min => (start + 1) as f64,
+                            RankMethod::Max => end as f64,
+                            RankMethod::Dense => dense as f64,
+                            RankMethod::Ordinal => (start + 1 + k) as f64,
+                        };
+                    }
+                    start = end;
+                }
+            });
+        ranks
+    }
+}
+
+/// Compare `a` and `b`, ordering elements that are not comparable with themselves
+/// (like NaN) after all others and equal to each other.
+fn cmp_nan_last<A: PartialOrd>(a: &A, b: &A) -> Ordering
+{
+    a.partial_cmp(b).unwrap_or_else(|| {
+        let a_nan = a.partial_cmp(a).is_none();
+        let b_nan = b.partial_cmp(b).is_none();
+        a_nan.cmp(&b_nan)
+    })
+}
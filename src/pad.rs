@@ -0,0 +1,169 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::imp_prelude::*;
+use crate::Slice;
+
+/// How to fill the padding added by [`pad_axis`](ArrayBase::pad_axis).
+///
+/// The modes match those of the same name in `numpy.pad`. For an axis with
+/// the elements `1 2 3`, padding two elements on each side gives:
+///
+/// ```text
+/// Constant(0)  0 0 | 1 2 3 | 0 0
+/// Edge         1 1 | 1 2 3 | 3 3
+/// Reflect      3 2 | 1 2 3 | 2 1
+/// Symmetric    2 1 | 1 2 3 | 3 2
+/// Wrap         2 3 | 1 2 3 | 1 2
+/// ```
+///
+/// Padding wider than the axis continues the same pattern.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PadMode<A>
+{
+    /// Fill the padding with the given value.
+    Constant(A),
+    /// Repeat the first and last element.
+    Edge,
+    /// Mirror the elements at the edges, without repeating the edge element.
+    Reflect,
+    /// Mirror the elements at the edges, including the edge element.
+    Symmetric,
+    /// Continue with the elements from the opposite end, as if the axis was
+    /// periodic.
+    Wrap,
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a copy of the array with `before` elements added at the start
+    /// and `after` elements added at the end of `axis`, filled according
+    /// to `mode`.
+    ///
+    /// The result has the same shape as `self`, except that its length along
+    /// `axis` is `before + self.len_of(axis) + after`.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the new length overflows, or
+    /// if `mode` is not `Constant`, padding is requested and the axis has
+    /// length zero.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis, PadMode};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(
+    ///     a.pad_axis(Axis(1), 1, 2, PadMode::Constant(0)),
+    ///     array![[0, 1, 2, 3, 0, 0],
+    ///            [0, 4, 5, 6, 0, 0]]
+    /// );
+    /// assert_eq!(
+    ///     a.pad_axis(Axis(0), 0, 3, PadMode::Reflect),
+    ///     array![[1, 2, 3],
+    ///            [4, 5, 6],
+    ///            [1, 2, 3],
+    ///            [4, 5, 6],
+    ///            [1, 2, 3]]
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn pad_axis(&self, axis: Axis, before: usize, after: usize, mode: PadMode<A>) -> Array<A, D>
+    where
+        A: Clone,
+        D: RemoveAxis,
+    {
+        let n = self.len_of(axis);
+        let new_len = before
+            .checked_add(n)
+            .and_then(|len| len.checked_add(after))
+            .expect("ndarray: pad_axis: new axis length overflows usize");
+        let (n, before) = (n as isize, before as isize);
+        if let PadMode::Constant(value) = mode {
+            let mut dim = self.raw_dim();
+            dim[axis.index()] = new_len;
+            let mut out = Array::from_elem(dim, value);
+            out.slice_axis_mut(axis, Slice::from(before..before + n))
+                .assign(self);
+            return out;
+        }
+        if new_len as isize == n {
+            return self.to_owned();
+        }
+        ndassert!(n > 0, "ndarray: pad_axis: an axis of length 0 can only be padded with PadMode::Constant");
+
+        // Map each index of the result to an index of `self`; reflection is periodic with period
+        // `2n - 2` (`Reflect`) or `2n` (`Symmetric`)
+        let indices: Vec<usize> = (-before..new_len as isize - before)
+            .map(|i| {
+                let i = match mode {
+                    PadMode::Edge => i.max(0).min(n - 1),
+                    PadMode::Wrap => i.rem_euclid(n),
+                    PadMode::Reflect if n == 1 => 0,
+                    PadMode::Reflect => {
+                        let i = i.rem_euclid(2 * n - 2);
+                        if i < n {
+                            i
+                        } else {
+                            2 * n - 2 - i
+                        }
+                    }
+                    PadMode::Symmetric => {
+                        let i = i.rem_euclid(2 * n);
+                        if i < n {
+                            i
+                        } else {
+                            2 * n - 1 - i
+                        }
+                    }
+                    PadMode::Constant(_) => unreachable!(),
+                };
+                i as usize
+            })
+            .collect();
+        self.select(axis, &indices)
+    }
+
+    /// Return a copy of the array with `axis` cropped or padded at the end to length `new_len`.
+    ///
+    /// If `new_len` is less than the length of `axis`, the elements from `new_len` on are
+    /// dropped; if it is greater, elements equal to `fill` are added at the end, like
+    /// [`.pad_axis(axis, 0, new_len - len, PadMode::Constant(fill))`](Self::pad_axis). This is
+    /// useful to bring sequences of different lengths to a common length. The result is always
+    /// a new array, even if the length is unchanged.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    ///
+    /// let a = array![[1, 2, 3],
+    ///                [4, 5, 6]];
+    /// assert_eq!(a.resize_axis(Axis(1), 2, 0), array![[1, 2], [4, 5]]);
+    /// assert_eq!(a.resize_axis(Axis(1), 5, 0), array![[1, 2, 3, 0, 0], [4, 5, 6, 0, 0]]);
+    /// assert_eq!(a.resize_axis(Axis(0), 3, -1), array![[1, 2, 3], [4, 5, 6], [-1, -1, -1]]);
+    /// ```
+    #[track_caller]
+    pub fn resize_axis(&self, axis: Axis, new_len: usize, fill: A) -> Array<A, D>
+    where
+        A: Clone,
+        D: RemoveAxis,
+    {
+        let n = self.len_of(axis);
+        if new_len <= n {
+            self.slice_axis(axis, Slice::from(..new_len)).to_owned()
+        } else {
+            self.pad_axis(axis, 0, new_len - n, PadMode::Constant(fill))
+        }
+    }
+}
@@ -27,6 +27,26 @@ where
         self.view_mut().into_par_iter().for_each(f)
     }
 
+    /// Parallel version of `map_inplace` with a minimum job size.
+    ///
+    /// Modify the array in place by calling `f` by mutable reference on each element.
+    ///
+    /// The array is not split into jobs of fewer than `min_len` elements, which reduces
+    /// the scheduling overhead when `f` is very cheap. This is a shorthand for
+    /// `.view_mut().into_par_iter().with_min_len(min_len).for_each(f)`.
+    ///
+    /// Elements are visited in arbitrary order.
+    ///
+    /// ***Panics*** if `min_len` is zero.
+    pub fn par_map_inplace_min_len<F>(&mut self, min_len: usize, f: F)
+    where F: Fn(&mut A) + Sync + Send
+    {
+        self.view_mut()
+            .into_par_iter()
+            .with_min_len(min_len)
+            .for_each(f)
+    }
+
     /// Parallel version of `mapv_inplace`.
     ///
     /// Modify the array in place by calling `f` by **v**alue on each element.
@@ -70,6 +90,73 @@ macro_rules! zip_impl {
                 self.into_par_iter().for_each(move |($($p,)*)| function($($p),*))
             }
 
+            /// Parallel version of `fold`.
+            ///
+            /// Splits the producer in multiple tasks which each accumulate a single value
+            /// using the `fold` closure. Those tasks are executed in parallel and their results
+            /// are then combined to a single value using the `reduce` closure.
+            ///
+            /// The `identity` closure provides the initial values for each of the tasks and
+            /// for the final reduction.
+            ///
+            /// This is a shorthand for calling `self.into_par_iter().fold(...).reduce(...)`.
+            ///
+            /// Note that it is often more efficient to parallelize not per-element but rather
+            /// based on larger chunks of an array like generalized rows and operating on each chunk
+            /// using a sequential variant of the accumulation.
+            /// For example, sum each row sequentially and in parallel, taking advantage of locality
+            /// and vectorization within each task, and then reduce their sums to the sum of the matrix.
+            ///
+            /// Both `fold` and `reduce` should be associative, and `identity` should produce a
+            /// neutral element for them; otherwise the result depends on how the producer was split.
+            ///
+            /// Also note that the splitting of the producer into multiple tasks is _not_ deterministic
+            /// which needs to be considered when the accuracy of such an operation is analyzed.
+            /// In particular, floating point addition is not associative, so a parallel sum of
+            /// floats can differ slightly from the sequential sum and between runs.
+            ///
+            /// ## Examples
+            ///
+            /// ```rust
+            /// use ndarray::{Array, Zip};
+            ///
+            /// let a = Array::<usize, _>::ones((128, 1024));
+            /// let b = Array::<usize, _>::ones(128);
+            ///
+            /// let weighted_sum = Zip::from(a.rows()).and(&b).par_fold(
+            ///     || 0,
+            ///     |sum, row, factor| sum + row.sum() * factor,
+            ///     |sum, other_sum| sum + other_sum,
+            /// );
+            ///
+            /// assert_eq!(weighted_sum, a.len());
+            ///
+            /// // Parallel dot product of two arrays
+            /// let x = Array::<usize, _>::from_elem(1000, 2);
+            /// let y = Array::<usize, _>::from_elem(1000, 3);
+            ///
+            /// let dot = Zip::from(&x).and(&y).par_fold(
+            ///     || 0,
+            ///     |acc, &x, &y| acc + x * y,
+            ///     |acc, other| acc + other,
+            /// );
+            ///
+            /// assert_eq!(dot, 6000);
+            /// ```
+            pub fn par_fold<ID, F, R, T>(self, identity: ID, fold: F, reduce: R) -> T
+            where
+                ID: Fn() -> T + Send + Sync + Clone,
+                F: Fn(T, $($p::Item),*) -> T + Send + Sync,
+                R: Fn(T, T) -> T + Send + Sync,
+                T: Send
+            {
+                self.into_par_iter()
+                    .fold(identity.clone(), move |accumulator, ($($p,)*)| {
+                        fold(accumulator, $($p),*)
+                    })
+                    .reduce(identity, reduce)
+            }
+
             expand_if!(@bool [$notlast]
 
             /// Map and collect the results into a new array, which has the same size as the
@@ -137,56 +224,6 @@ macro_rules! zip_impl {
                     });
             }
 
-            /// Parallel version of `fold`.
-            ///
-            /// Splits the producer in multiple tasks which each accumulate a single value
-            /// using the `fold` closure. Those tasks are executed in parallel and their results
-            /// are then combined to a single value using the `reduce` closure.
-            ///
-            /// The `identity` closure provides the initial values for each of the tasks and
-            /// for the final reduction.
-            ///
-            /// This is a shorthand for calling `self.into_par_iter().fold(...).reduce(...)`.
-            ///
-            /// Note that it is often more efficient to parallelize not per-element but rather
-            /// based on larger chunks of an array like generalized rows and operating on each chunk
-            /// using a sequential variant of the accumulation.
-            /// For example, sum each row sequentially and in parallel, taking advantage of locality
-            /// and vectorization within each task, and then reduce their sums to the sum of the matrix.
-            ///
-            /// Also note that the splitting of the producer into multiple tasks is _not_ deterministic
-            /// which needs to be considered when the accuracy of such an operation is analyzed.
-            ///
-            /// ## Examples
-            ///
-            /// ```rust
-            /// use ndarray::{Array, Zip};
-            ///
-            /// let a = Array::<usize, _>::ones((128, 1024));
-            /// let b = Array::<usize, _>::ones(128);
-            ///
-            /// let weighted_sum = Zip::from(a.rows()).and(&b).par_fold(
-            ///     || 0,
-            ///     |sum, row, factor| sum + row.sum() * factor,
-            ///     |sum, other_sum| sum + other_sum,
-            /// );
-            ///
-            /// assert_eq!(weighted_sum, a.len());
-            /// ```
-            pub fn par_fold<ID, F, R, T>(self, identity: ID, fold: F, reduce: R) -> T
-            where
-                ID: Fn() -> T + Send + Sync + Clone,
-                F: Fn(T, $($p::Item),*) -> T + Send + Sync,
-                R: Fn(T, T) -> T + Send + Sync,
-                T: Send
-            {
-                self.into_par_iter()
-                    .fold(identity.clone(), move |accumulator, ($($p,)*)| {
-                        fold(accumulator, $($p),*)
-                    })
-                    .reduce(identity, reduce)
-            }
-
             );
         }
         )+
@@ -75,6 +75,28 @@
 //! assert_eq!(sums, [120., 376., 632., 888.]);
 //! ```
 //!
+//! ## One result per subview into an output array
+//!
+//! The axis iterators are also producers for [Zip], so they can be zipped with an
+//! output array that has one element per subview. Each output element is paired
+//! with exactly one subview, so every position is written by exactly one task.
+//!
+//! ```
+//! use ndarray::{Array, Array1, Axis, Zip};
+//!
+//! let a = Array::linspace(0., 63., 64).into_shape_with_order((4, 4, 4)).unwrap();
+//! let mut maxima = Array1::zeros(a.len_of(Axis(2)));
+//!
+//! Zip::from(&mut maxima)
+//!     .and(a.axis_iter(Axis(2)))
+//!     .par_for_each(|max, subview| *max = subview.fold(f64::MIN, |m, &x| m.max(x)));
+//! assert_eq!(maxima, Array1::from(vec![60., 61., 62., 63.]));
+//!
+//! // or collect the results into a new array
+//! let sums = Zip::from(a.axis_iter(Axis(0))).par_map_collect(|subview| subview.sum());
+//! assert_eq!(sums, Array1::from(vec![120., 376., 632., 888.]));
+//! ```
+//!
 //! ## Axis chunks iterators
 //!
 //! Use the parallel `.axis_chunks_iter()` to process your data in chunks.
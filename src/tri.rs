@@ -6,6 +6,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 use core::cmp::min;
 
 use num_traits::Zero;
@@ -13,9 +15,11 @@ use num_traits::Zero;
 use crate::{
     dimension::{is_layout_c, is_layout_f},
     Array,
+    Array2,
     ArrayBase,
     Axis,
     Data,
+    DataMut,
     Dimension,
     Zip,
 };
@@ -154,13 +158,199 @@ where
     }
 }
 
+impl<S, A, D> ArrayBase<S, D>
+where
+    S: DataMut<Elem = A>,
+    D: Dimension,
+    A: Clone,
+{
+    /// Set the elements below the *k*-th diagonal to `value`, in place.
+    ///
+    /// These are the elements that [`ArrayBase::triu`] zeroes, so that
+    /// `a.fill_lower(0, k)` makes `a` equal to `a.triu(k)`. The array does not
+    /// need to be square.
+    /// For arrays with `ndim` exceeding 2, `fill_lower` will apply to the final two axes.
+    /// For 0D and 1D arrays, `fill_lower` does nothing.
+    ///
+    /// See also [`ArrayBase::fill_upper`]
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// let mut arr = array![
+    ///     [1, 2, 3, 4],
+    ///     [5, 6, 7, 8],
+    ///     [9, 10, 11, 12]
+    /// ];
+    /// arr.fill_lower(0, 1);
+    /// assert_eq!(
+    ///     arr,
+    ///     array![
+    ///         [0, 2, 3, 4],
+    ///         [0, 0, 7, 8],
+    ///         [0, 0, 0, 12]
+    ///     ]
+    /// );
+    /// ```
+    pub fn fill_lower(&mut self, value: A, k: isize)
+    {
+        self.fill_tri_rows(value, |row_num, ncols| {
+            let lower = match k >= 0 {
+                true => row_num.saturating_add(k as usize),
+                false => row_num.saturating_sub(k.unsigned_abs()),
+            };
+            0..min(lower, ncols)
+        });
+    }
+
+    /// Set the elements above the *k*-th diagonal to `value`, in place.
+    ///
+    /// These are the elements that [`ArrayBase::tril`] zeroes, so that
+    /// `a.fill_upper(0, k)` makes `a` equal to `a.tril(k)`. The array does not
+    /// need to be square.
+    /// For arrays with `ndim` exceeding 2, `fill_upper` will apply to the final two axes.
+    /// For 0D and 1D arrays, `fill_upper` does nothing.
+    ///
+    /// See also [`ArrayBase::fill_lower`]
+    ///
+    /// ```
+    /// use ndarray::array;
+    ///
+    /// // A causal mask: position i may only attend to positions j <= i
+    /// let mut mask = array![[0., 0., 0.], [0., 0., 0.], [0., 0., 0.]];
+    /// mask.fill_upper(f64::NEG_INFINITY, 0);
+    /// assert_eq!(
+    ///     mask,
+    ///     array![
+    ///         [0., f64::NEG_INFINITY, f64::NEG_INFINITY],
+    ///         [0., 0., f64::NEG_INFINITY],
+    ///         [0., 0., 0.]
+    ///     ]
+    /// );
+    /// ```
+    pub fn fill_upper(&mut self, value: A, k: isize)
+    {
+        self.fill_tri_rows(value, |row_num, ncols| {
+            let upper = match k >= 0 {
+                true => row_num.saturating_add(k as usize).saturating_add(1),
+                false => row_num.saturating_sub((k + 1).unsigned_abs()),
+            };
+            min(upper, ncols)..ncols
+        });
+    }
+
+    /// Fill `value` into the columns `columns(row_num, ncols)` of each row of the final two axes
+    fn fill_tri_rows<F>(&mut self, value: A, columns: F)
+    where F: Fn(usize, usize) -> core::ops::Range<usize>
+    {
+        if self.ndim() <= 1 {
+            return;
+        }
+        let n = self.ndim();
+        let ncols = self.len_of(Axis(n - 1));
+        let nrows = self.len_of(Axis(n - 2));
+        let indices = Array::from_iter(0..nrows);
+        Zip::from(self.rows_mut())
+            .and_broadcast(&indices)
+            .for_each(|mut row, &row_num| {
+                row.slice_mut(s![columns(row_num, ncols)])
+                    .fill(value.clone());
+            });
+    }
+}
+
+/// Return the index pairs of the main diagonal of an `n × n` array.
+///
+/// The result has shape `(n, 2)`, and row `i` is the index pair `[i, i]`.
+///
+/// ```
+/// use ndarray::{array, diag_indices};
+///
+/// assert_eq!(diag_indices(3), array![[0, 0], [1, 1], [2, 2]]);
+/// ```
+pub fn diag_indices(n: usize) -> Array2<usize>
+{
+    Array2::from_shape_fn((n, 2), |(i, _)| i)
+}
+
+/// Return the index pairs of the upper triangle of an `n × n` array, from the
+/// *k*-th diagonal and up.
+///
+/// These are the elements that [`ArrayBase::triu`] keeps: the index pairs
+/// `[i, j]` with `j >= i + k`. The result has shape `(count, 2)` and the index
+/// pairs are in row-major order (ordered by `i`, then by `j`), the same as
+/// those returned by [`nonzero`](ArrayBase::nonzero).
+///
+/// See also [`tril_indices`]
+///
+/// ```
+/// use ndarray::{array, triu_indices};
+///
+/// assert_eq!(triu_indices(3, 0), array![[0, 0], [0, 1], [0, 2], [1, 1], [1, 2], [2, 2]]);
+/// assert_eq!(triu_indices(3, 2), array![[0, 2]]);
+/// ```
+pub fn triu_indices(n: usize, k: isize) -> Array2<usize>
+{
+    tri_indices(n, |i| {
+        let lower = match k >= 0 {
+            true => i.saturating_add(k as usize),
+            false => i.saturating_sub(k.unsigned_abs()),
+        };
+        min(lower, n)..n
+    })
+}
+
+/// Return the index pairs of the lower triangle of an `n × n` array, from the
+/// *k*-th diagonal and down.
+///
+/// These are the elements that [`ArrayBase::tril`] keeps: the index pairs
+/// `[i, j]` with `j <= i + k`. The result has shape `(count, 2)` and the index
+/// pairs are in row-major order (ordered by `i`, then by `j`), the same as
+/// those returned by [`nonzero`](ArrayBase::nonzero).
+///
+/// See also [`triu_indices`]
+///
+/// ```
+/// use ndarray::{array, tril_indices};
+///
+/// assert_eq!(tril_indices(3, 0), array![[0, 0], [1, 0], [1, 1], [2, 0], [2, 1], [2, 2]]);
+/// assert_eq!(tril_indices(3, -1), array![[1, 0], [2, 0], [2, 1]]);
+/// ```
+pub fn tril_indices(n: usize, k: isize) -> Array2<usize>
+{
+    tri_indices(n, |i| {
+        let upper = match k >= 0 {
+            true => i.saturating_add(k as usize).saturating_add(1),
+            false => i.saturating_sub((k + 1).unsigned_abs()),
+        };
+        0..min(upper, n)
+    })
+}
+
+/// Collect the index pairs `[i, j]` for each row `i` in `0..n` and each `j` in `columns(i)`
+fn tri_indices<F>(n: usize, mut columns: F) -> Array2<usize>
+where F: FnMut(usize) -> core::ops::Range<usize>
+{
+    let mut indices = Vec::new();
+    for i in 0..n {
+        for j in columns(i) {
+            indices.push(i);
+            indices.push(j);
+        }
+    }
+    let count = indices.len() / 2;
+    Array2::from_shape_vec((count, 2), indices).unwrap()
+}
+
 #[cfg(test)]
 mod tests
 {
     use core::isize;
 
+    use super::{diag_indices, tril_indices, triu_indices};
     use crate::{array, dimension, Array0, Array1, Array2, Array3, ShapeBuilder};
     use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn test_keep_order()
@@ -364,4 +554,55 @@ mod tests
         assert_eq!(x.triu(isize::MAX), z);
         assert_eq!(x.tril(isize::MAX), x);
     }
+
+    #[test]
+    fn test_tri_indices()
+    {
+        let x = Array2::from_shape_fn((4, 4), |(i, j)| 1 + i * 4 + j);
+        for k in -5..=5 {
+            for (indices, tri) in [(triu_indices(4, k), x.triu(k)), (tril_indices(4, k), x.tril(k))] {
+                assert_eq!(indices.ncols(), 2);
+                let picked: Vec<_> = indices
+                    .rows()
+                    .into_iter()
+                    .map(|ij| x[[ij[0], ij[1]]])
+                    .collect();
+                let kept: Vec<_> = tri.iter().cloned().filter(|&v| v != 0).collect();
+                assert_eq!(picked, kept);
+            }
+        }
+        assert_eq!(triu_indices(2, isize::MIN), array![[0, 0], [0, 1], [1, 0], [1, 1]]);
+        assert_eq!(tril_indices(2, isize::MIN).shape(), &[0, 2]);
+        assert_eq!(triu_indices(0, 0).shape(), &[0, 2]);
+        assert_eq!(diag_indices(0).shape(), &[0, 2]);
+        assert_eq!(diag_indices(2), array![[0, 0], [1, 1]]);
+    }
+
+    #[test]
+    fn test_fill_tri()
+    {
+        for &shape in &[(3, 3), (3, 5), (5, 3)] {
+            let x = Array2::from_shape_fn(shape, |(i, j)| 1 + i * 5 + j);
+            for k in -6..=6 {
+                let mut lower = x.clone();
+                lower.fill_lower(0, k);
+                assert_eq!(lower, x.triu(k));
+                let mut upper = x.t().to_owned();
+                upper.fill_upper(0, k);
+                assert_eq!(upper, x.t().tril(k));
+            }
+        }
+
+        let mut x = Array3::<i32>::zeros((2, 3, 3).f());
+        x.fill_upper(1, 0);
+        x.fill_lower(-1, 0);
+        for mat in x.outer_iter() {
+            assert_eq!(mat, array![[0, 1, 1], [-1, 0, 1], [-1, -1, 0]]);
+        }
+
+        let mut x = array![1, 2, 3];
+        x.fill_lower(0, isize::MAX);
+        x.fill_upper(0, isize::MIN);
+        assert_eq!(x, array![1, 2, 3]);
+    }
 }
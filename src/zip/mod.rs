@@ -728,6 +728,18 @@ macro_rules! map_impl {
             ///
             /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
             ///
+            /// Broadcasting uses zero strides and does not allocate, so a single value
+            /// can be included as a constant input using a zero-dimensional view of it:
+            ///
+            /// ```
+            /// use ndarray::{aview0, array, Zip};
+            ///
+            /// let a = array![[1., 2.], [3., 4.]];
+            /// let k = 10.;
+            /// let b = Zip::from(&a).and_broadcast(aview0(&k)).map_collect(|&x, &k| x * k);
+            /// assert_eq!(b, array![[10., 20.], [30., 40.]]);
+            /// ```
+            ///
             /// ***Panics*** if broadcasting isn’t possible.
             #[track_caller]
             pub fn and_broadcast<'a, P, D2, Elem>(self, p: P)
@@ -782,6 +794,43 @@ macro_rules! map_impl {
                 }
             }
 
+            /// Map and collect the results into a new array, which has the same size as the
+            /// inputs, stopping at the first error.
+            ///
+            /// If `f` returns an error, it is returned and the elements collected so far are
+            /// dropped; elements after the failing one are not visited. The order of visiting
+            /// is the same as in `map_collect`, which is not necessarily the logical order.
+            ///
+            /// If all inputs are c- or f-order respectively, that is preserved in the output.
+            ///
+            /// ```
+            /// use ndarray::{array, Zip};
+            ///
+            /// let a = array![[1u8, 200], [3, 4]];
+            /// let b = array![[5u8, 6], [7, 8]];
+            /// let sum = Zip::from(&a).and(&b).try_map_collect(|&x, &y| x.checked_add(y).ok_or(x));
+            /// assert_eq!(sum, Ok(array![[6, 206], [10, 12]]));
+            ///
+            /// let b = array![[5u8, 60], [7, 8]];
+            /// let sum = Zip::from(&a).and(&b).try_map_collect(|&x, &y| x.checked_add(y).ok_or(x));
+            /// assert_eq!(sum, Err(200));
+            /// ```
+            pub fn try_map_collect<R, E>(self, f: impl FnMut($($p::Item,)* ) -> Result<R, E>)
+                -> Result<Array<R, D>, E>
+            {
+                let shape = self.dimension.clone().set_f(self.prefer_f());
+                let mut output = Array::uninit(shape);
+                // safe because: all elements are written if no error is returned, and on error
+                // (or unwinding) the partial drops the elements written so far.
+                unsafe {
+                    let output_view = output.raw_view_mut().cast::<R>();
+                    self.and(output_view)
+                        .try_collect_with_partial(f)?
+                        .release_ownership();
+                    Ok(output.assume_init())
+                }
+            }
+
             /// Map and assign the results into the producer `into`, which should have the same
             /// size as the other inputs.
             ///
@@ -865,6 +914,56 @@ macro_rules! map_impl {
 
                     partial
                 }
+
+                /// Like `collect_with_partial`, but stops at the first error, which is returned
+                /// after the partial has dropped the elements written so far.
+                ///
+                /// ## Safety
+                ///
+                /// As for `collect_with_partial`.
+                pub(crate) unsafe fn try_collect_with_partial<F, E>(self, mut f: F)
+                    -> Result<Partial<R>, E>
+                    where F: FnMut($($p::Item,)* ) -> Result<R, E>
+                {
+                    let (.., ref output) = &self.parts;
+
+                    // debug assert that the output is contiguous in the memory layout we need
+                    if cfg!(debug_assertions) {
+                        let out_layout = output.layout();
+                        assert!(out_layout.is(Layout::CORDER | Layout::FORDER));
+                        assert!(
+                            (self.layout_tendency <= 0 && out_layout.tendency() <= 0) ||
+                            (self.layout_tendency >= 0 && out_layout.tendency() >= 0),
+                            "layout tendency violation for self layout {:?}, output layout {:?},\
+                            output shape {:?}",
+                            self.layout, out_layout, output.raw_dim());
+                    }
+
+                    let mut partial = Partial::new(output.as_ptr());
+                    let mut error = None;
+
+                    let partial_len = &mut partial.len;
+                    self.fold_while((), |(), $($p,)* output_elem: *mut R| {
+                        match f($($p),*) {
+                            Ok(elem) => {
+                                output_elem.write(elem);
+                                if std::mem::needs_drop::<R>() {
+                                    *partial_len += 1;
+                                }
+                                FoldWhile::Continue(())
+                            }
+                            Err(e) => {
+                                error = Some(e);
+                                FoldWhile::Done(())
+                            }
+                        }
+                    });
+
+                    match error {
+                        None => Ok(partial),
+                        Some(e) => Err(e),
+                    }
+                }
             }
         );
 
@@ -404,6 +404,31 @@ fn push_row_neg_stride_3()
     assert_eq!(a.strides(), &[4, -1]);
 }
 
+#[test]
+fn append_relayout()
+{
+    // Layouts where `axis` is not the growing axis are relaid out, never rejected
+    let mut a = Array::from_shape_vec((2, 3).f(), vec![0, 3, 1, 4, 2, 5]).unwrap();
+    a.append(Axis(0), aview2(&[[6, 7, 8]])).unwrap();
+    assert_eq!(a, array![[0, 1, 2], [3, 4, 5], [6, 7, 8]]);
+
+    let mut a = Array::from_iter(0..12)
+        .into_shape_with_order((4, 3))
+        .unwrap();
+    a.slice_collapse(s![1..3, ..]);
+    a.append(Axis(0), aview2(&[[12, 13, 14]])).unwrap();
+    assert_eq!(a, array![[3, 4, 5], [6, 7, 8], [12, 13, 14]]);
+
+    let mut a = Array::from_iter(0..6)
+        .into_shape_with_order((2, 3))
+        .unwrap();
+    a.invert_axis(Axis(0));
+    a.append(Axis(0), aview2(&[[6, 7, 8], [9, 10, 11]]))
+        .unwrap();
+    assert_eq!(a, array![[3, 4, 5], [0, 1, 2], [6, 7, 8], [9, 10, 11]]);
+    assert!(a.stride_of(Axis(0)) > 0);
+}
+
 #[test]
 fn push_row_ignore_strides_length_one_axes()
 {
@@ -451,3 +476,41 @@ fn zero_dimensional_ok()
     a.push(Axis(0), one).unwrap();
     assert_eq!(a, array![2, 1, 1]);
 }
+
+#[test]
+fn extend_axis()
+{
+    let mut a = Array::zeros((0, 3));
+    let b = Array::from_iter(0..12)
+        .into_shape_with_order((4, 3))
+        .unwrap();
+    a.extend_axis(Axis(0), b.outer_iter()).unwrap();
+    assert_eq!(a, b);
+
+    // columns, including views with negative strides
+    a.extend_axis(Axis(1), b.slice(s![..;-1, ..2]).columns())
+        .unwrap();
+    assert_eq!(a.column(3), array![9, 6, 3, 0]);
+    assert_eq!(a.column(4), array![10, 7, 4, 1]);
+    assert_eq!(a.shape(), &[4, 5]);
+
+    a.extend_axis(Axis(0), vec![]).unwrap();
+    assert_eq!(a.shape(), &[4, 5]);
+}
+
+#[test]
+fn extend_axis_error()
+{
+    let mut a = Array::from_iter(0..6)
+        .into_shape_with_order((2, 3))
+        .unwrap();
+    let good = array![6, 7, 8];
+    let bad = array![1, 2];
+    assert_eq!(a.extend_axis(Axis(0), vec![good.view(), bad.view()]),
+        Err(ShapeError::from_kind(ErrorKind::IncompatibleShape)));
+    // nothing was appended
+    assert_eq!(a, array![[0, 1, 2], [3, 4, 5]]);
+
+    let mut z = Array::zeros(()).into_dyn();
+    assert!(z.extend_axis(Axis(0), vec![arr0(0).into_dyn().view()]).is_err());
+}
@@ -138,6 +138,234 @@ fn test_from_fn_f3()
     }
 }
 
+#[test]
+fn test_try_from_shape_fn()
+{
+    let a = Array::try_from_shape_fn((2, 3), |(i, j)| Ok::<_, ()>(i * 10 + j));
+    assert_eq!(a, Ok(arr2(&[[0, 1, 2], [10, 11, 12]])));
+
+    let a = Array::try_from_shape_fn((2, 3).f(), |(i, j)| Ok::<_, ()>(i * 10 + j)).unwrap();
+    assert_eq!(a, arr2(&[[0, 1, 2], [10, 11, 12]]));
+    assert!(a.t().is_standard_layout());
+}
+
+#[test]
+fn test_try_from_shape_fn_error()
+{
+    use std::rc::Rc;
+
+    let counter = Rc::new(());
+    let mut calls = 0;
+    let result = Array::try_from_shape_fn((4, 5), |(i, j)| {
+        calls += 1;
+        if (i, j) == (1, 2) {
+            Err("bad index")
+        } else {
+            Ok(counter.clone())
+        }
+    });
+    assert_eq!(result, Err("bad index"));
+    assert_eq!(calls, 8);
+    // all the elements created before the error have been dropped
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn test_from_shape_lanes()
+{
+    let expected = Array::from_shape_fn((2, 3, 4), |(i, j, k)| i * 100 + j * 10 + k);
+    for ax in 0..3 {
+        let mut calls = Vec::new();
+        let a = Array3::from_shape_lanes((2, 3, 4), Axis(ax), |index| {
+            calls.push(index);
+            let mut ijk = [index.0, index.1, 0];
+            ijk[ax..].rotate_right(1);
+            Array::from_iter((0..expected.len_of(Axis(ax))).map(|x| {
+                ijk[ax] = x;
+                expected[ijk]
+            }))
+        });
+        assert_eq!(a, expected);
+        // the lanes are visited in logical order
+        let mut sorted = calls.clone();
+        sorted.sort();
+        assert_eq!(calls, sorted);
+        assert_eq!(calls.len(), 24 / expected.len_of(Axis(ax)));
+    }
+
+    let rows = Array2::from_shape_lanes((2, 3), Axis(1), |i| Array1::from_elem(3, i));
+    assert_eq!(rows, arr2(&[[0, 0, 0], [1, 1, 1]]));
+    assert!(rows.is_standard_layout());
+    let v = Array1::from_shape_lanes(3, Axis(0), |()| arr1(&[1, 2, 3]));
+    assert_eq!(v, arr1(&[1, 2, 3]));
+    let e = Array2::<u8>::from_shape_lanes((0, 3), Axis(1), |_| unreachable!());
+    assert_eq!(e.shape(), &[0, 3]);
+}
+
+#[test]
+#[should_panic(expected = "has length 2, but the axis length is 3")]
+fn test_from_shape_lanes_wrong_length()
+{
+    Array2::from_shape_lanes((2, 3), Axis(1), |i| Array1::from_elem(2 + i, i));
+}
+
+#[test]
+fn test_from_rows_columns()
+{
+    let lanes = vec![arr1(&[1, 2, 3]), arr1(&[4, 5, 6])];
+    let views: Vec<_> = lanes.iter().map(|lane| lane.view()).collect();
+
+    let a = Array2::from_rows(&views).unwrap();
+    assert_eq!(a, arr2(&[[1, 2, 3], [4, 5, 6]]));
+    assert!(a.is_standard_layout());
+
+    let b = Array2::from_columns(&views).unwrap();
+    assert_eq!(b, arr2(&[[1, 4], [2, 5], [3, 6]]));
+    assert!(b.is_standard_layout());
+
+    // rows can be non-contiguous views
+    let m = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    let cols: Vec<_> = m.columns().into_iter().collect();
+    assert_eq!(Array2::from_rows(&cols).unwrap(), m.t());
+}
+
+#[test]
+fn test_from_rows_columns_empty()
+{
+    let a = Array2::<i32>::from_rows(&[]).unwrap();
+    assert_eq!(a.dim(), (0, 0));
+    let a = Array2::<i32>::from_columns(&[]).unwrap();
+    assert_eq!(a.dim(), (0, 0));
+
+    let empty = Array1::<i32>::zeros(0);
+    let a = Array2::from_rows(&[empty.view(), empty.view()]).unwrap();
+    assert_eq!(a.dim(), (2, 0));
+    let a = Array2::from_columns(&[empty.view(), empty.view()]).unwrap();
+    assert_eq!(a.dim(), (0, 2));
+}
+
+#[test]
+fn test_from_rows_columns_mismatch()
+{
+    let a = arr1(&[1, 2]);
+    let b = arr1(&[3]);
+    let err = Array2::from_rows(&[a.view(), b.view(), a.view()]).unwrap_err();
+    assert_eq!(err.axis(), Axis(0));
+    assert_eq!(err.index(), Some(1));
+    assert_eq!(err.found_len(), Some(1));
+    assert_eq!(err.expected_len(), 2);
+    assert_eq!(err.to_string(), "LaneLengthError: row 1 has length 1, but the first row has length 2");
+    assert_eq!(ndarray::ShapeError::from(err).kind(), ndarray::ErrorKind::IncompatibleShape);
+
+    let err = Array2::from_columns(&[a.view(), a.view(), b.view()]).unwrap_err();
+    assert_eq!(err.axis(), Axis(1));
+    assert_eq!(err.index(), Some(2));
+    assert_eq!(err.found_len(), Some(1));
+    assert_eq!(err.expected_len(), 2);
+    assert_eq!(err.to_string(), "LaneLengthError: column 2 has length 1, but the first column has length 2");
+
+    let big = ArrayView1::from(&[(); isize::MAX as usize][..]);
+    let err = Array2::from_rows(&[big, big]).unwrap_err();
+    assert_eq!(err.index(), None);
+    assert_eq!(ndarray::ShapeError::from(err).kind(), ndarray::ErrorKind::Overflow);
+}
+
+#[test]
+fn test_from_bands()
+{
+    let a = Array2::from_bands(3, &[(2, aview1(&[3])), (-1, aview1(&[4, 5])), (0, aview1(&[1, 1, 1]))]);
+    assert_eq!(a, arr2(&[[1, 0, 3], [4, 1, 0], [0, 5, 1]]));
+
+    // bands outside of the matrix are empty
+    let a = Array2::<i32>::from_bands(2, &[(5, aview1(&[])), (-2, aview1(&[]))]);
+    assert_eq!(a, Array2::<i32>::zeros((2, 2)));
+
+    let a = Array2::<i32>::from_bands(0, &[]);
+    assert_eq!(a.dim(), (0, 0));
+}
+
+#[should_panic]
+#[test]
+fn test_from_bands_wrong_length()
+{
+    let _ = Array2::<i32>::from_bands(3, &[(1, aview1(&[1, 2, 3]))]);
+}
+
+#[test]
+fn test_from_boxed_slice()
+{
+    let b: Box<[i32]> = (0..6).collect();
+    let ptr = b.as_ptr();
+    let a = Array::from_boxed_slice((2, 3), b).unwrap();
+    assert_eq!(a, arr2(&[[0, 1, 2], [3, 4, 5]]));
+    assert_eq!(a.as_ptr(), ptr);
+
+    let b: Box<[i32]> = (0..6).collect();
+    let a = Array::from_boxed_slice((2, 3).f(), b).unwrap();
+    assert_eq!(a, arr2(&[[0, 2, 4], [1, 3, 5]]));
+
+    let b: Box<[i32]> = (0..5).collect();
+    assert!(Array::from_boxed_slice((2, 3), b).is_err());
+}
+
+#[test]
+fn test_try_from_nested_vec()
+{
+    use std::convert::TryFrom;
+
+    let a = Array2::try_from(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+    assert_eq!(a, arr2(&[[1, 2], [3, 4], [5, 6]]));
+
+    let a = Array2::try_from(Vec::<Vec<i32>>::new()).unwrap();
+    assert_eq!(a.dim(), (0, 0));
+    let a = Array2::<i32>::try_from(vec![vec![], vec![]]).unwrap();
+    assert_eq!(a.dim(), (2, 0));
+
+    let err = Array2::try_from(vec![vec![1, 2], vec![3, 4], vec![5], vec![]]).unwrap_err();
+    assert_eq!(err.index(), Some(2));
+    assert_eq!(err.found_len(), Some(1));
+    assert_eq!(err.expected_len(), 2);
+    assert_eq!(err.to_string(), "LaneLengthError: row 2 has length 1, but the first row has length 2");
+    assert_eq!(ndarray::ShapeError::from(err).kind(), ndarray::ErrorKind::IncompatibleShape);
+
+    let err = Array2::try_from(vec![vec![(); usize::MAX], vec![(); usize::MAX]]).unwrap_err();
+    assert_eq!(err.index(), None);
+    assert_eq!(ndarray::ShapeError::from(err).kind(), ndarray::ErrorKind::Overflow);
+
+    let a = ArcArray::<String, Ix2>::try_from(vec![vec![String::from("a")], vec![String::from("b")]]).unwrap();
+    assert_eq!(a[[1, 0]], "b");
+}
+
+#[test]
+fn test_from_be_le_bytes()
+{
+    let values = arr2(&[[1.5f64, -2.], [1e10, 0.25], [f64::MAX, 3.]]);
+    let be: Vec<u8> = values.iter().flat_map(|x| x.to_be_bytes()).collect();
+    let le: Vec<u8> = values.iter().flat_map(|x| x.to_le_bytes()).collect();
+    assert_eq!(Array2::<f64>::from_be_bytes((3, 2), &be).unwrap(), values);
+    assert_eq!(Array2::<f64>::from_le_bytes((3, 2), &le).unwrap(), values);
+
+    // unaligned bytes and f-order shape
+    let ints = [1i32, -2, 3, 70000];
+    let mut be = vec![0u8];
+    be.extend(ints.iter().flat_map(|x| x.to_be_bytes()));
+    let a = Array2::<i32>::from_be_bytes((2, 2).f(), &be[1..]).unwrap();
+    assert_eq!(a, arr2(&[[1, 3], [-2, 70000]]));
+
+    // swapping the bytes converts between the two
+    let mut b = Array2::<i32>::from_le_bytes((2, 2).f(), &be[1..]).unwrap();
+    b.swap_bytes_inplace();
+    assert_eq!(b, a);
+    let mut v = values.clone();
+    v.slice_mut(s![.., 0]).swap_bytes_inplace();
+    v.slice_mut(s![.., 0]).swap_bytes_inplace();
+    assert_eq!(v, values);
+
+    let err = Array2::<i32>::from_be_bytes((2, 2), &be[..15]).unwrap_err();
+    assert_eq!(err.kind(), ndarray::ErrorKind::IncompatibleShape);
+    assert!(Array1::<u16>::from_le_bytes(0, &[]).unwrap().is_empty());
+}
+
 #[test]
 fn deny_wraparound_from_vec()
 {
@@ -305,3 +533,23 @@ fn maybe_uninit_1()
         Zip::from(u).for_each(|ptr| assert_eq!(*ptr, 1.));
     }
 }
+
+#[test]
+fn test_vec_unvec()
+{
+    let x = array![[1, 2, 3], [4, 5, 6]];
+    let v = x.vec_column_major();
+    assert_eq!(v, array![1, 4, 2, 5, 3, 6]);
+    // the order doesn't depend on the memory layout
+    assert_eq!(x.t().to_owned().reversed_axes().vec_column_major(), v);
+    assert_eq!(x.t().vec_column_major(), array![1, 2, 3, 4, 5, 6]);
+
+    let y = Array2::unvec(&v, (2, 3)).unwrap();
+    assert_eq!(y, x);
+    assert!(y.t().is_standard_layout());
+    let w = Array2::unvec(&v.slice(s![..;-1]), (3, 2)).unwrap();
+    assert_eq!(w, array![[6, 2], [3, 4], [5, 1]]);
+
+    assert!(Array2::<i32>::unvec(&v, (4, 2)).is_err());
+    assert_eq!(Array2::<i32>::unvec(&Array1::zeros(0), (0, 3)).unwrap().dim(), (0, 3));
+}
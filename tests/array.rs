@@ -623,6 +623,25 @@ fn test_negative_stride_arcarray()
     }
 }
 
+#[test]
+fn test_negative_stride_signed()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let v = a.slice(s![..;-1, .., ..;-2]);
+    assert_eq!(v.strides(), &[-12, 4, -2]);
+    assert_eq!(v.stride_of(Axis(0)), -12);
+    assert_eq!(v.stride_of(Axis(2)), -2);
+    for ax in v.axes() {
+        assert_eq!(ax.stride, v.stride_of(ax.axis));
+    }
+
+    let mut t = a.clone().reversed_axes();
+    t.invert_axis(Axis(0));
+    assert_eq!(t.strides(), &[-1, 4, 12]);
+}
+
 #[test]
 fn test_cow()
 {
@@ -762,6 +781,23 @@ fn test_select_1d()
     assert_eq!(r3, arr1(&[]));
 }
 
+#[test]
+fn test_dedup_consecutive_axis()
+{
+    let a = array![[[1, 1], [2, 2]], [[1, 1], [2, 2]], [[1, 1], [2, 3]], [[1, 1], [2, 2]]];
+    assert_eq!(a.dedup_consecutive_axis(Axis(0)), a.select(Axis(0), &[0, 2, 3]));
+    assert_eq!(a.dedup_consecutive_axis(Axis(2)), a);
+    let b = Array3::<u8>::zeros((2, 5, 3));
+    assert_eq!(b.dedup_consecutive_axis(Axis(1)), Array3::zeros((2, 1, 3)));
+
+    // reversed axis and 1-D arrays
+    assert_eq!(array![1, 2, 2, 3, 3, 3].slice(s![..;-1]).dedup_consecutive_axis(Axis(0)), array![3, 2, 1]);
+    assert_eq!(array![1, 2, 1].dedup_consecutive_axis(Axis(0)), array![1, 2, 1]);
+
+    let empty = Array2::<i32>::zeros((0, 3));
+    assert_eq!(empty.dedup_consecutive_axis(Axis(0)).shape(), &[0, 3]);
+}
+
 #[test]
 fn diag()
 {
@@ -883,6 +919,27 @@ fn swapaxes()
     assert_eq!(b.as_slice_memory_order(), Some(&[1., 3., 2., 4.][..]));
 }
 
+#[test]
+fn view_into_slice_memory_order()
+{
+    let a = Array::from_iter(0..6)
+        .into_shape_with_order((2, 3))
+        .unwrap();
+    let slice = {
+        let v = a.view();
+        v.reversed_axes().into_slice_memory_order()
+    };
+    assert_eq!(slice, Some(&[0, 1, 2, 3, 4, 5][..]));
+
+    // negative strides
+    let mut v = a.view();
+    v.invert_axis(Axis(1));
+    assert_eq!(v.to_slice(), None);
+    assert_eq!(v.into_slice_memory_order(), Some(&[0, 1, 2, 3, 4, 5][..]));
+
+    assert_eq!(a.slice(s![.., ..2]).into_slice_memory_order(), None);
+}
+
 #[test]
 fn permuted_axes()
 {
@@ -950,6 +1007,46 @@ fn permuted_axes_oob()
     a.view().permuted_axes([1, 0, 3]);
 }
 
+#[test]
+fn permute_axes_dyn()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let order = vec![2, 0, 1];
+    let p = a.view().permute_axes_dyn(&order);
+    assert_eq!(p.shape(), &[4, 2, 3]);
+    assert_eq!(p, a.view().permuted_axes([2, 0, 1]).into_dyn());
+    assert_eq!(p[[3, 1, 2]], a[[1, 2, 3]]);
+
+    let d = a.into_dyn().permute_axes_dyn(&[0, 1, 2]);
+    assert_eq!(d.shape(), &[2, 3, 4]);
+}
+
+#[should_panic(expected = "has length 2")]
+#[test]
+fn permute_axes_dyn_wrong_length()
+{
+    let a = Array3::<f32>::zeros((2, 3, 4));
+    a.permute_axes_dyn(&[1, 0]);
+}
+
+#[should_panic(expected = "is repeated")]
+#[test]
+fn permute_axes_dyn_repeated_axis()
+{
+    let a = Array3::<f32>::zeros((2, 3, 4));
+    a.permute_axes_dyn(&[1, 0, 1]);
+}
+
+#[should_panic(expected = "out of bounds")]
+#[test]
+fn permute_axes_dyn_oob()
+{
+    let a = Array3::<f32>::zeros((2, 3, 4));
+    a.permute_axes_dyn(&[1, 0, 3]);
+}
+
 #[test]
 fn standard_layout()
 {
@@ -969,6 +1066,37 @@ fn standard_layout()
     assert!(x4.is_standard_layout());
 }
 
+#[test]
+fn fortran_layout_and_contiguous()
+{
+    let mut a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    assert!(a.is_contiguous());
+    assert!(!a.is_fortran_layout());
+    a.swap_axes(0, 2);
+    assert!(a.is_fortran_layout());
+    assert!(a.is_contiguous());
+    a.swap_axes(0, 1);
+    assert!(!a.is_fortran_layout() && !a.is_standard_layout());
+    assert!(a.is_contiguous());
+    a.invert_axis(Axis(2));
+    assert!(a.is_contiguous());
+    assert!(!a.slice(s![.., 1.., ..]).is_contiguous());
+
+    // length-1 axes and one-dimensional arrays
+    let x1 = ArrayView2::from_shape((1, 3).strides((7, 1)), &[1, 2, 3]).unwrap();
+    assert!(x1.is_standard_layout() && x1.is_contiguous());
+    // a single row is in both standard and F order
+    assert!(x1.is_fortran_layout());
+    let x2 = ArrayView2::from_shape((3, 1).strides((1, 7)), &[1, 2, 3]).unwrap();
+    assert!(x2.is_fortran_layout() && x2.is_contiguous());
+    let v = arr1(&[1, 2, 3]);
+    assert!(v.is_standard_layout() && v.is_fortran_layout());
+    assert!(v.slice(s![..;-1]).is_contiguous());
+    assert!(!v.slice(s![..;-1]).is_fortran_layout());
+}
+
 #[test]
 fn iter_size_hint()
 {
@@ -1049,6 +1177,160 @@ fn map1()
     assert_eq!(a[(0, 0)], *c[(0, 0)]);
 }
 
+#[test]
+fn try_map()
+{
+    let a = arr2(&[[1, 2], [3, 4]]);
+    let b = a.try_map(|&x| if x > 0 { Ok(x * 2) } else { Err(x) });
+    assert_eq!(b, Ok(arr2(&[[2, 4], [6, 8]])));
+
+    // memory order of a non-standard layout array is kept
+    let mut v = a.view();
+    v.swap_axes(0, 1);
+    let b = v.try_mapv(|x| Ok::<_, ()>(x)).unwrap();
+    assert_eq!(b, v);
+    assert_eq!(b.strides(), v.strides());
+
+    // non-contiguous input
+    let b = a.slice(s![.., ..;2]).try_mapv(|x| Ok::<_, ()>(x + 1));
+    assert_eq!(b, Ok(arr2(&[[2], [4]])));
+}
+
+#[test]
+fn try_mapv_error()
+{
+    use std::rc::Rc;
+
+    let counter = Rc::new(());
+    let a = Array::from_shape_fn((3, 4), |(i, j)| i * 4 + j);
+    let mut calls = 0;
+    let b = a.try_mapv(|x| {
+        calls += 1;
+        if x == 6 {
+            Err(x)
+        } else {
+            Ok(counter.clone())
+        }
+    });
+    assert_eq!(b, Err(6));
+    assert_eq!(calls, 7);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn fill_with()
+{
+    let mut a = Array::zeros((3, 4));
+    let mut counter = 0;
+    a.fill_with(|| {
+        counter += 1;
+        counter
+    });
+    assert_eq!(a, Array::from_shape_vec((3, 4), (1..13).collect()).unwrap());
+
+    // Non-contiguous view: only the visited elements are overwritten
+    let mut calls = 0;
+    a.slice_mut(s![.., ..;2]).fill_with(|| {
+        calls += 1;
+        0
+    });
+    assert_eq!(calls, 6);
+    assert_eq!(a, arr2(&[[0, 2, 0, 4], [0, 6, 0, 8], [0, 10, 0, 12]]));
+}
+
+#[test]
+fn masked_fill()
+{
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| i * 4 + j);
+    let even = a.mapv(|x| x % 2 == 0);
+    a.slice_mut(s![.., ..;-1])
+        .masked_fill(&even.slice(s![.., ..;-1]), 0);
+    assert_eq!(a, arr2(&[[0, 1, 0, 3], [0, 5, 0, 7], [0, 9, 0, 11]]));
+
+    // broadcast a column mask
+    a.masked_fill(&array![[true], [false], [true]], 1);
+    assert_eq!(a, arr2(&[[1, 1, 1, 1], [0, 5, 0, 7], [1, 1, 1, 1]]));
+
+    let mut calls = 0;
+    let zero = a.t().mapv(|x| x == 0);
+    a.view_mut().reversed_axes().masked_fill_with(&zero, || {
+        calls += 1;
+        2
+    });
+    assert_eq!(calls, 2);
+    assert_eq!(a.row(1), aview1(&[2, 5, 2, 7]));
+}
+
+#[test]
+#[should_panic]
+fn masked_fill_bad_shape()
+{
+    let mut a = Array2::<i32>::zeros((3, 4));
+    a.masked_fill(&Array1::from_elem(3, true), 1);
+}
+
+#[test]
+fn zip_map()
+{
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    let b = arr2(&[[1., 0., 1.], [0., 1., 0.]]);
+    let c = a.zip_map(&b, |&x, &y| x as f64 * y);
+    assert_eq!(c, arr2(&[[1., 0., 3.], [0., 5., 0.]]));
+    assert!(c.is_standard_layout());
+
+    // broadcast rhs, output layout follows self
+    let row = arr1(&[true, false, true]);
+    for a in [a.view(), a.t().t()] {
+        let c = a.zip_map(&row, |&x, &keep| if keep { x } else { 0 });
+        assert_eq!(c, arr2(&[[1, 0, 3], [4, 0, 6]]));
+    }
+    let mut f = Array2::zeros((2, 3).f());
+    f.assign(&a);
+    let c = f.zip_map(&row, |&x, &keep| keep && x > 1);
+    assert_eq!(c, arr2(&[[false, false, true], [true, false, true]]));
+    assert!(c.t().is_standard_layout());
+    let c = f.zip_map(&arr2(&[[1], [2]]), |&x, &y| x * y);
+    assert_eq!(c, arr2(&[[1, 2, 3], [8, 10, 12]]));
+    assert!(c.t().is_standard_layout());
+    let c = a.zip_map(&arr2(&[[1], [2]]), |&x, &y| x * y);
+    assert!(c.is_standard_layout());
+    let c = f.zip_map(&arr0(1), |&x, &y| x + y);
+    assert_eq!(c, arr2(&[[2, 3, 4], [5, 6, 7]]));
+    assert!(c.t().is_standard_layout());
+}
+
+#[test]
+#[should_panic]
+fn zip_map_broadcast_fail()
+{
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    a.zip_map(&arr1(&[1, 2]), |&x, &y| x + y);
+}
+
+#[test]
+fn fold_with()
+{
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    let b = arr2(&[[6, 5, 4], [3, 2, 1]]);
+    assert_eq!(a.fold_with(&b, 0, |acc, &x, &y| acc + x * y), 56);
+    assert_eq!(a.t().fold_with(&b.t(), 0, |acc, &x, &y| acc.max(x - y)), 5);
+
+    // broadcast rhs
+    assert_eq!(a.fold_with(&arr1(&[1, 0, -1]), 0, |acc, &x, &y| acc + x * y), -4);
+    assert_eq!(a.fold_with(&arr0(2), 0, |acc, &x, &y| acc + x * y), 42);
+
+    let empty = Array2::<i32>::zeros((0, 3));
+    assert_eq!(empty.fold_with(&arr1(&[1, 2, 3]), 7, |_, _, _| 0), 7);
+}
+
+#[test]
+#[should_panic]
+fn fold_with_broadcast_fail()
+{
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    a.fold_with(&arr1(&[1, 2]), 0, |acc, &x, &y| acc + x + y);
+}
+
 #[test]
 fn mapv_into_any_same_type()
 {
@@ -1065,6 +1347,87 @@ fn mapv_into_any_diff_types()
     assert_eq!(a.mapv_into_any(|a| a.round() as i32 % 2 == 0), a_even);
 }
 
+#[test]
+fn mapv_into_same_size()
+{
+    let a = array![[1i32, -2, 3], [-4, 5, -6]];
+    let ptr = a.as_ptr();
+    let b = a.mapv_into_same_size(|x| x.unsigned_abs());
+    assert_eq!(b, array![[1u32, 2, 3], [4, 5, 6]]);
+    assert_eq!(b.as_ptr() as *const i32, ptr);
+
+    // strides are kept
+    let mut a = array![[1, 2, 3], [4, 5, 6]];
+    a.swap_axes(0, 1);
+    a.invert_axis(Axis(0));
+    let b = a.clone().mapv_into_same_size(|x| x as u32 * 2);
+    assert_eq!(b, a.mapv(|x| x as u32 * 2));
+    assert_eq!(b.strides(), a.strides());
+
+    // unreachable elements are dropped
+    let mut a = Array::from_shape_fn((4, 5), |(i, j)| (i * 5 + j).to_string());
+    a.slice_collapse(s![1..3, ..;2]);
+    let b = a.mapv_into_same_size(|s| s + "!");
+    assert_eq!(b, array![["5!", "7!", "9!"], ["10!", "12!", "14!"]].mapv(String::from));
+}
+
+#[test]
+fn mapv_into_same_size_panic()
+{
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    let old = Rc::new(());
+    let new = Rc::new(());
+    let a = Array::from_elem((3, 4), old.clone());
+    let mut calls = 0;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        a.mapv_into_same_size(|_| {
+            calls += 1;
+            if calls == 6 {
+                panic!("map failed");
+            }
+            new.clone()
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(calls, 6);
+    assert_eq!(Rc::strong_count(&old), 1);
+    assert_eq!(Rc::strong_count(&new), 1);
+}
+
+#[test]
+fn to_shared_view()
+{
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    let v = a.slice(s![.., ..;2]);
+    let shared = v.to_shared();
+    assert_eq!(shared, v);
+    assert!(shared.is_unique());
+    assert_ne!(shared.as_ptr(), v.as_ptr());
+
+    let shared2 = shared.to_shared();
+    assert_eq!(shared2.as_ptr(), shared.as_ptr());
+    assert!(!shared.is_unique());
+}
+
+#[test]
+fn arcarray_make_mut()
+{
+    let mut a = rcarr2(&[[1., 2.], [3., 4.0f32]]);
+    let ptr = a.as_ptr();
+    a.make_mut().fill(1.);
+    assert_eq!(a.as_ptr(), ptr, "unique data should not be cloned");
+
+    let b = a.clone();
+    a.make_mut()[[0, 1]] = 0.;
+    assert_ne!(a.as_ptr(), b.as_ptr());
+    assert!(a.is_unique());
+    assert!(b.is_unique());
+    assert_eq!(a, arr2(&[[1., 0.], [1., 1.]]));
+    assert_eq!(b, arr2(&[[1., 1.], [1., 1.]]));
+}
+
 #[test]
 fn as_slice_memory_order_mut_arcarray()
 {
@@ -1736,6 +2099,44 @@ fn insert_axis_view()
     );
 }
 
+#[test]
+fn insert_axes()
+{
+    let a = Array::from_iter(0..6)
+        .into_shape_with_order((2, 3))
+        .unwrap();
+    let chained = a
+        .clone()
+        .insert_axis(Axis(0))
+        .insert_axis(Axis(2))
+        .into_dyn();
+    assert_eq!(a.view().insert_axes(&[0, 2]), chained);
+    assert_eq!(a.view().insert_axes(&[2, 0]), chained);
+    assert_eq!(a.view().insert_axes(&[2, 3, 4]).shape(), &[2, 3, 1, 1, 1]);
+
+    // data and strides of the existing axes are unchanged
+    let t = a.t().insert_axes(&[1]);
+    assert_eq!(t.shape(), &[3, 1, 2]);
+    assert_eq!(t.index_axis(Axis(1), 0), a.t().into_dyn());
+
+    let s = arr0(5).insert_axes(&[0, 1]);
+    assert_eq!(s, array![[5]].into_dyn());
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn insert_axes_out_of_bounds()
+{
+    Array2::<u8>::zeros((2, 3)).insert_axes(&[0, 4]);
+}
+
+#[test]
+#[should_panic(expected = "repeated")]
+fn insert_axes_repeated()
+{
+    Array2::<u8>::zeros((2, 3)).insert_axes(&[1, 1]);
+}
+
 #[test]
 fn arithmetic_broadcast()
 {
@@ -1901,6 +2302,49 @@ fn deny_split_at_index_out_of_bounds()
     a.view().split_at(Axis(1), 3);
 }
 
+#[test]
+fn split_first_last()
+{
+    let a = Array::from_iter(0..12)
+        .into_shape_with_order((3, 2, 2))
+        .unwrap();
+    let (first, rest) = a.view().split_first().unwrap();
+    assert_eq!(first, a.index_axis(Axis(0), 0));
+    assert_eq!(rest, a.slice(s![1.., .., ..]));
+    let (last, rest) = a.view().split_last().unwrap();
+    assert_eq!(last, a.index_axis(Axis(0), 2));
+    assert_eq!(rest, a.slice(s![..2, .., ..]));
+
+    let single = a.slice(s![..1, .., ..]);
+    let (first, rest) = single.split_first().unwrap();
+    assert_eq!(first, a.index_axis(Axis(0), 0));
+    assert_eq!(rest.shape(), &[0, 2, 2]);
+    assert!(rest.split_first().is_none());
+    assert!(rest.split_last().is_none());
+
+    // reversed axis
+    let (first, _) = a.slice(s![..;-1, .., ..]).split_first().unwrap();
+    assert_eq!(first, a.index_axis(Axis(0), 2));
+
+    assert!(ArrayD::<i32>::zeros(IxDyn(&[])).view().split_first().is_none());
+}
+
+#[test]
+fn split_first_last_mut()
+{
+    let mut a = Array::from_iter(0..6)
+        .into_shape_with_order((3, 2))
+        .unwrap();
+    {
+        let (mut first, mut rest) = a.view_mut().split_first().unwrap();
+        first += 10;
+        let (mut last, _) = rest.view_mut().split_last().unwrap();
+        last += 100;
+        rest[[0, 0]] = -1;
+    }
+    assert_eq!(a, array![[10, 11], [-1, 3], [104, 105]]);
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn test_range()
@@ -1986,6 +2430,21 @@ fn to_owned_neg_stride()
     assert_eq!(c.strides(), co.strides());
 }
 
+#[test]
+fn to_owned_empty_with_strides()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let (empty, _) = a.view().split_at(Axis(0), 0);
+    assert_eq!(empty.strides(), &[12, 4, 1]);
+    let owned = empty.to_owned();
+    assert_eq!(owned.shape(), &[0, 3, 4]);
+    assert_eq!(owned, empty);
+    assert_eq!(empty.map(|x| x + 1).shape(), &[0, 3, 4]);
+    assert_eq!(empty.try_map(|&x| Ok::<_, ()>(x)).unwrap().shape(), &[0, 3, 4]);
+}
+
 #[test]
 fn discontiguous_owned_to_owned()
 {
@@ -1998,6 +2457,29 @@ fn discontiguous_owned_to_owned()
     assert_eq!(c, co);
 }
 
+#[test]
+fn to_owned_f()
+{
+    let c = arr3(&[[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [0, -1, -2]]]);
+    let f = c.to_owned_f();
+    assert_eq!(f, c);
+    assert_eq!(f.strides(), &[1, 2, 4]);
+    assert!(f.t().is_standard_layout());
+
+    // already f-contiguous, negative strides and discontiguous input
+    let ff = f.to_owned_f();
+    assert_eq!(ff, c);
+    assert_eq!(ff.strides(), &[1, 2, 4]);
+    for v in &[c.slice(s![..;-1, .., ..]), c.slice(s![.., .., ..;2])] {
+        let vf = v.to_owned_f();
+        assert_eq!(vf, v);
+        assert!(vf.t().is_standard_layout());
+    }
+
+    let e = Array3::<i32>::zeros((2, 0, 3)).to_owned_f();
+    assert_eq!(e.shape(), &[2, 0, 3]);
+}
+
 #[test]
 fn map_memory_order()
 {
@@ -2401,6 +2883,36 @@ fn test_accumulate_axis_inplace_nonstandard_layout() {
                             [12, 23, 33]]));
 }
 
+#[test]
+fn test_accumulate_axis_inplace_indexed()
+{
+    // same as accumulate_axis_inplace when the index is ignored
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    for axis in 0..3 {
+        let mut expected = a.clone();
+        expected.accumulate_axis_inplace(Axis(axis), |&prev, curr| *curr += prev);
+        let mut b = a.clone();
+        b.accumulate_axis_inplace_indexed(Axis(axis), |_, &prev, curr| *curr += prev);
+        assert_eq!(b, expected);
+    }
+
+    // the index is that of the current element, starting from 1
+    let mut c = Array2::<usize>::zeros((2, 4));
+    c.invert_axis(Axis(1));
+    let mut seen = Vec::new();
+    c.accumulate_axis_inplace_indexed(Axis(1), |i, &prev, curr| {
+        seen.push(i);
+        *curr = prev * 10 + i;
+    });
+    assert_eq!(c, array![[0, 1, 12, 123], [0, 1, 12, 123]]);
+    assert_eq!(seen, [1, 2, 3, 1, 2, 3]);
+
+    let mut d = Array2::<u8>::zeros((3, 1));
+    d.accumulate_axis_inplace_indexed(Axis(1), |_, _, _| panic!());
+}
+
 #[test]
 fn test_to_vec()
 {
@@ -2723,6 +3235,41 @@ fn test_remove_index()
                []]);
 }
 
+#[test]
+fn test_swap_remove_index()
+{
+    let mut a = arr2(&[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]);
+    a.swap_remove_index(Axis(0), 0);
+    assert_eq!(a, array![[10, 11, 12], [4, 5, 6], [7, 8, 9]]);
+    a.swap_remove_index(Axis(1), 1);
+    assert_eq!(a, array![[10, 12], [4, 6], [7, 9]]);
+    // removing the last one just shortens the axis
+    a.swap_remove_index(Axis(0), 2);
+    assert_eq!(a, array![[10, 12], [4, 6]]);
+
+    let mut b = arr2(&[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]);
+    b.invert_axis(Axis(0));
+    b.swap_remove_index(Axis(0), 1);
+    assert_eq!(b, array![[10, 11, 12], [1, 2, 3], [4, 5, 6]]);
+
+    // elements with ownership are moved, not cloned or dropped
+    let mut c = Array1::from_iter((0..4).map(|i| i.to_string()));
+    c.swap_remove_index(Axis(0), 1);
+    assert_eq!(c, array!["0".to_string(), "3".to_string(), "2".to_string()]);
+    c.swap_remove_index(Axis(0), 0);
+    c.swap_remove_index(Axis(0), 0);
+    c.swap_remove_index(Axis(0), 0);
+    assert_eq!(c.len(), 0);
+}
+
+#[should_panic(expected = "must be less")]
+#[test]
+fn test_swap_remove_index_oob()
+{
+    let mut a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    a.swap_remove_index(Axis(1), 3);
+}
+
 #[should_panic(expected = "must be less")]
 #[test]
 fn test_remove_index_oob1()
@@ -2789,6 +3336,18 @@ fn test_split_complex_view_mut()
     assert_eq!(a, eye_complex);
 }
 
+#[test]
+fn test_from_re_im()
+{
+    let a = Array::from_shape_fn((3, 4), |(i, j)| Complex::new(i as i32, -(j as i32)));
+    let t = a.t();
+    let b = Array2::from_re_im(t.re(), t.im()).unwrap();
+    assert_eq!(b, t);
+    let r = Array::from_shape_fn((3, 4), |(i, j)| i + j);
+    let err = Array2::<Complex<usize>>::from_re_im(r.view(), r.t()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+}
+
 #[test]
 fn test_split_complex_zerod()
 {
@@ -2820,3 +3379,369 @@ fn test_split_complex_invert_axis()
     assert_eq!(cmplx.re, a.mapv(|z| z.re));
     assert_eq!(cmplx.im, a.mapv(|z| z.im));
 }
+
+#[test]
+fn test_is_sorted()
+{
+    let a = array![[1, 1, 2], [0, 5, 7], [3, 4, 6]];
+    assert!(a.is_sorted_axis(Axis(1)));
+    assert!(!a.is_sorted_axis(Axis(0)));
+    assert!(a.slice(s![1.., ..1]).is_sorted_axis(Axis(0)));
+    assert!(a.slice(s![..;-1, ..;-1]).is_sorted_axis_by(Axis(1), |a, b| a >= b));
+    assert!(!a.t().is_sorted_axis(Axis(1)));
+
+    assert!(Array2::<i32>::zeros((0, 3)).is_sorted_axis(Axis(1)));
+    assert!(Array2::<i32>::zeros((3, 0)).is_sorted_axis(Axis(1)));
+    assert!(array![3, 1, 2].slice(s![..1]).is_sorted());
+    assert!(!array![1., f64::NAN, 2.].is_sorted());
+
+    // stops at the first pair out of order
+    let mut calls = 0;
+    let sorted = array![1, 0, 3, 4, 5].is_sorted_axis_by(Axis(0), |a, b| {
+        calls += 1;
+        a <= b
+    });
+    assert!(!sorted);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn test_try_for_each()
+{
+    let a = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    assert_eq!(a.try_for_each(|&x| if x < 12 { Ok(()) } else { Err(x) }), Ok(()));
+    assert_eq!(a.try_for_each(|&x| if x < 5 { Ok(()) } else { Err(x) }), Err(5));
+
+    // logical order is used for non-standard layouts as well
+    let t = a.t();
+    assert_eq!(t.try_for_each(|&x| if x < 5 { Ok(()) } else { Err(x) }), Err(8));
+
+    let mut visited = 0;
+    let res = a.slice(s![.., ..;2]).try_for_each(|&x| {
+        visited += 1;
+        if x % 3 == 0 {
+            Err(x)
+        } else {
+            Ok(())
+        }
+    });
+    assert_eq!(res, Err(0));
+    assert_eq!(visited, 1);
+}
+
+#[test]
+fn test_trim_zeros()
+{
+    use ndarray::TrimMode;
+
+    let a = array![0, 0, 1, 0, 2, 0];
+    let both = a.trim_zeros(TrimMode::Both);
+    assert_eq!(both, array![1, 0, 2]);
+    assert_eq!(both.as_ptr(), &a[2] as *const _);
+    assert_eq!(a.trim_zeros(TrimMode::Front), array![1, 0, 2, 0]);
+    assert_eq!(a.trim_zeros(TrimMode::Back), array![0, 0, 1, 0, 2]);
+
+    let s = a.slice(s![..;-1]);
+    assert_eq!(s.trim_zeros(TrimMode::Both), array![2, 0, 1]);
+
+    for mode in [TrimMode::Front, TrimMode::Back, TrimMode::Both] {
+        assert_eq!(array![0, 0, 0].trim_zeros(mode).len(), 0);
+        assert_eq!(Array1::<f32>::zeros(0).trim_zeros(mode).len(), 0);
+        assert_eq!(array![3].trim_zeros(mode), array![3]);
+    }
+}
+
+#[test]
+fn test_put()
+{
+    let mut a = Array::zeros((2, 3, 2));
+    a.put(aview1(&[0, 3, 11, 3]), aview1(&[1, 2, 3, 4]));
+    let mut expected = Array::zeros(12);
+    expected[0] = 1;
+    expected[3] = 4;
+    expected[11] = 3;
+    assert_eq!(a, expected.into_shape_with_order((2, 3, 2)).unwrap());
+
+    // flat indices are in logical order for any layout
+    let mut b = Array::zeros((3, 4).f());
+    b.slice_mut(s![..;-1, 1..])
+        .put(aview1(&[0, 4]), aview1(&[7, 8]));
+    assert_eq!(b, array![[0, 0, 0, 0], [0, 0, 8, 0], [0, 7, 0, 0]]);
+
+    let mut c = arr0(0);
+    c.put(aview1(&[0]), aview1(&[5]));
+    assert_eq!(c, arr0(5));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_put_oob()
+{
+    let mut a = Array2::<u8>::zeros((2, 3));
+    a.put(aview1(&[6]), aview1(&[1]));
+}
+
+#[test]
+fn test_take()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    assert_eq!(a.take(aview1(&[23, 0, 5, 5])), array![23, 0, 5, 5]);
+    assert_eq!(a.take(aview1(&[])).len(), 0);
+
+    let v = a.slice(s![.., ..;-1, 1..;2]);
+    let flat: Vec<_> = v.iter().cloned().collect();
+    let idx = [0, 11, 3, 7];
+    assert_eq!(v.take(aview1(&idx)), idx.iter().map(|&i| flat[i]).collect::<Array1<_>>());
+
+    assert_eq!(arr0(3).take(aview1(&[0, 0])), array![3, 3]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_take_oob()
+{
+    Array::<f32, _>::zeros((0, 3)).take(aview1(&[0]));
+}
+
+#[test]
+fn flatten_into_owned()
+{
+    let a = Array::from_iter(0..6)
+        .into_shape_with_order((2, 3))
+        .unwrap();
+    for v in &[a.view(), a.t(), a.slice(s![.., ..;-1])] {
+        let flat: Array1<i32> = v.flatten().into_owned();
+        assert!(flat.is_standard_layout());
+        assert_eq!(flat.to_vec(), v.iter().cloned().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn split_at_axis_owned()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        for index in 0..=a.len_of(axis) {
+            let (first, second) = a.clone().split_at_axis(axis, index);
+            let (v1, v2) = a.view().split_at(axis, index);
+            assert_eq!(first, v1);
+            assert_eq!(second, v2);
+            assert!(first.is_standard_layout() && second.is_standard_layout());
+        }
+    }
+
+    let f = a.reversed_axes();
+    let (first, second) = f.clone().split_at_axis(Axis(0), 3);
+    assert_eq!(first, f.slice(s![..3, .., ..]));
+    assert_eq!(second.shape(), &[1, 3, 2]);
+    std::thread::spawn(move || assert_eq!(second, array![[[3, 15], [7, 19], [11, 23]]]))
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn test_partition_point_axis()
+{
+    let a = array![[1, 3, 5, 7], [2, 2, 2, 2], [8, 9, 10, 11]];
+    assert_eq!(a.partition_point_axis(Axis(1), |&x| x < 5), array![2, 4, 0]);
+    for (lane, &p) in a
+        .rows()
+        .into_iter()
+        .zip(&a.partition_point_axis(Axis(1), |&x| x <= 2))
+    {
+        assert_eq!(p, lane.to_vec().partition_point(|&x| x <= 2));
+    }
+    assert_eq!(array![[1, 5], [2, 6], [3, 9]].partition_point_axis(Axis(0), |&x| x < 6), array![3, 1]);
+    assert_eq!(a.slice(s![.., ..;-1]).partition_point_axis(Axis(1), |&x| x > 5), array![1, 0, 4]);
+
+    let e = Array2::<i32>::zeros((3, 0));
+    assert_eq!(e.partition_point_axis(Axis(1), |_| true), array![0, 0, 0]);
+    assert_eq!(e.partition_point_axis(Axis(0), |_| true).len(), 0);
+}
+
+#[test]
+fn test_swapped_axes()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let mut b = a.view();
+    b.swap_axes(0, 2);
+    let c = a.clone().swapped_axes(0, 2);
+    assert_eq!(c, b);
+    assert_eq!(c.strides(), b.strides());
+    assert_eq!(c.swapped_axes(1, 1).swapped_axes(2, 0), a);
+}
+
+#[test]
+#[should_panic]
+fn test_swapped_axes_oob()
+{
+    Array2::<u8>::zeros((2, 2)).swapped_axes(0, 2);
+}
+
+#[test]
+fn test_cast_elem()
+{
+    let a = array![[-2.5f32, 0.5], [1e10, -1e10]];
+    assert_eq!(a.cast_elem::<i32>(), array![[-2, 0], [i32::MAX, i32::MIN]]);
+    assert_eq!(a.t().cast_elem::<i64>(), array![[-2, 10_000_000_000], [0, -10_000_000_000]]);
+    assert_eq!(array![300i32, -1].cast_elem::<u8>(), array![44, 255]);
+
+    assert_eq!(array![[1u16, 2], [3, 4]].try_cast_elem::<i8>(), Ok(array![[1i8, 2], [3, 4]]));
+    assert!(array![1u16, 200].try_cast_elem::<i8>().is_err());
+    assert_eq!(array![1u8, 200].try_cast_elem::<u16>(), Ok(array![1u16, 200]));
+}
+
+#[test]
+fn first_last_logical_order()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    assert_eq!(a.first(), Some(&0));
+    assert_eq!(a.last(), Some(&23));
+
+    // not the first and last elements in memory
+    let t = a.t();
+    assert_eq!(t.first(), Some(&0));
+    assert_eq!(t.last(), Some(&23));
+    let s = a.slice(s![..;-1, 1.., ..;-2]);
+    assert_eq!(s.first(), Some(&19));
+    assert_eq!(s.last(), Some(&9));
+
+    let mut b = a.clone().into_dyn();
+    *b.first_mut().unwrap() = -1;
+    *b.last_mut().unwrap() = -2;
+    assert_eq!(b[[0, 0, 0]], -1);
+    assert_eq!(b[[1, 2, 3]], -2);
+
+    assert_eq!(Array2::<i32>::zeros((3, 0)).last(), None);
+    assert_eq!(arr0(5).first(), Some(&5));
+}
+
+#[test]
+fn to_nested_vec()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let nested = a.to_nested_vec3();
+    assert_eq!(nested.len(), 2);
+    for (i, mat) in nested.iter().enumerate() {
+        assert_eq!(mat, &a.index_axis(Axis(0), i).to_nested_vec2());
+        assert_eq!(Array2::try_from(mat.clone()).unwrap(), a.index_axis(Axis(0), i));
+    }
+
+    let f = a.slice(s![.., ..;-1, 1]).reversed_axes();
+    assert_eq!(f.to_nested_vec2(), vec![vec![9, 21], vec![5, 17], vec![1, 13]]);
+
+    assert_eq!(Array2::<u8>::zeros((2, 0)).to_nested_vec2(), vec![Vec::<u8>::new(); 2]);
+    assert!(Array3::<u8>::zeros((0, 2, 2)).to_nested_vec3().is_empty());
+}
+
+#[test]
+fn fold_visits_each_element_once()
+{
+    let a = Array::from_iter(0..60)
+        .into_shape_with_order((3, 4, 5))
+        .unwrap();
+    for v in [a.view(), a.t(), a.slice(s![..;-1, 1..;2, ..;3]), a.slice(s![.., ..0, ..])] {
+        let mut seen = v.fold(Vec::new(), |mut acc, &x| {
+            acc.push(x);
+            acc
+        });
+        seen.sort();
+        let mut expected = v.iter().cloned().collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(seen, expected);
+        assert_eq!(v.fold(0, |acc, &x| acc + x), v.sum());
+    }
+}
+
+#[test]
+fn flip()
+{
+    let a = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    let lr = a.view().flip_lr();
+    let ud = a.view().flip_ud();
+    assert_eq!(lr, Array2::from_shape_fn((3, 4), |(i, j)| a[[i, 3 - j]]));
+    assert_eq!(ud, Array2::from_shape_fn((3, 4), |(i, j)| a[[2 - i, j]]));
+    // views of the same data
+    assert_eq!(lr.as_ptr(), &a[[0, 3]] as *const _);
+    assert_eq!(ud.as_ptr(), &a[[2, 0]] as *const _);
+    assert_eq!(ud.flip_ud(), a);
+    assert_eq!(a.clone().flip_lr().flip_ud(), a.slice(s![..;-1, ..;-1]));
+
+    let mut b = a.clone();
+    b.view_mut().flip_lr().row_mut(0).fill(-1);
+    assert_eq!(b.row(0), array![-1, -1, -1, -1]);
+
+    let c = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    assert_eq!(c.view().flipped_axis(Axis(2)), c.slice(s![.., .., ..;-1]));
+    assert_eq!(Array2::<u8>::zeros((0, 3)).flip_ud().shape(), &[0, 3]);
+}
+
+#[test]
+fn roll()
+{
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| i * 100 + j * 10 + k);
+    let shifts = [1, -6, 12];
+    let r = a.roll(&shifts);
+    for ((i, j, k), &x) in r.indexed_iter() {
+        let src = (
+            (i as isize - shifts[0]).rem_euclid(3) as usize,
+            (j as isize - shifts[1]).rem_euclid(4) as usize,
+            (k as isize - shifts[2]).rem_euclid(5) as usize,
+        );
+        assert_eq!(x, a[src]);
+    }
+    let chained = a
+        .roll_axis(Axis(0), shifts[0])
+        .roll_axis(Axis(1), shifts[1])
+        .roll_axis(Axis(2), shifts[2]);
+    assert_eq!(r, chained);
+
+    assert_eq!(a.roll(&[0, 4, -5]), a);
+    let v = a.slice(s![..;-1, 1.., ..;2]);
+    assert_eq!(v.roll(&[2, 1, 1]), v.to_owned().roll(&[2, 1, 1]));
+    let e = Array2::<i32>::zeros((0, 3));
+    assert_eq!(e.roll(&[1, 1]).shape(), &[0, 3]);
+    assert_eq!(arr0(7).roll(&[]), arr0(7));
+}
+
+#[test]
+fn fftshift()
+{
+    for &(m, n) in &[(4, 6), (5, 7), (4, 7), (1, 0)] {
+        let a = Array::from_shape_fn((m, n), |(i, j)| i * 10 + j);
+        let shifted = a.fftshift();
+        assert_eq!(shifted, a.roll(&[(m / 2) as isize, (n / 2) as isize]));
+        assert_eq!(shifted.ifftshift(), a);
+        if m > 0 && n > 0 {
+            // the zero-frequency element moves to the center
+            assert_eq!(shifted[(m / 2, n / 2)], 0);
+        }
+    }
+    let odd = array![0., 1., 2., 3., -3., -2., -1.];
+    assert_eq!(odd.fftshift(), array![-3., -2., -1., 0., 1., 2., 3.]);
+    assert_eq!(odd.ifftshift(), array![3., -3., -2., -1., 0., 1., 2.]);
+}
+
+#[test]
+#[should_panic(expected = "shifts given")]
+fn roll_bad_shifts()
+{
+    Array2::<i32>::zeros((2, 2)).roll(&[1]);
+}
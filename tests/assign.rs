@@ -28,6 +28,57 @@ fn assign()
     assert_eq!(a, arr2(&[[0, 0], [3, 4]]));
 }
 
+#[test]
+fn assign_broadcast_rows_columns()
+{
+    let mut a = Array2::zeros((3, 4));
+    a.assign(&arr1(&[1, 2, 3, 4]));
+    assert_eq!(a, arr2(&[[1, 2, 3, 4], [1, 2, 3, 4], [1, 2, 3, 4]]));
+
+    a.assign(&arr2(&[[5], [6], [7]]));
+    assert_eq!(a, arr2(&[[5, 5, 5, 5], [6, 6, 6, 6], [7, 7, 7, 7]]));
+}
+
+#[test]
+#[should_panic(expected = "could not broadcast")]
+fn assign_broadcast_fail()
+{
+    let mut a = Array2::<i32>::zeros((3, 4));
+    a.assign(&arr1(&[1, 2, 3]));
+}
+
+#[test]
+fn assign_block()
+{
+    let mut a = Array3::zeros((2, 3, 4));
+    a.assign_block((1, 1, 0), &Array::from_elem((1, 2, 4), 1));
+    assert_eq!(a.sum(), 8);
+    assert_eq!(a.slice(s![1, 1.., ..]), Array::from_elem((2, 4), 1));
+
+    // an empty block at the end is fine
+    a.assign_block((2, 3, 4), &Array3::zeros((0, 0, 0)));
+
+    let mut d = ArrayD::zeros(vec![3, 3]);
+    d.assign_block(IxDyn(&[0, 1]), &ArrayD::from_elem(vec![3, 2], 2));
+    assert_eq!(d, arr2(&[[0, 2, 2], [0, 2, 2], [0, 2, 2]]).into_dyn());
+}
+
+#[test]
+#[should_panic(expected = "does not fit")]
+fn assign_block_out_of_bounds()
+{
+    let mut a = Array2::<f64>::zeros((3, 4));
+    a.assign_block((2, 0), &Array2::ones((2, 2)));
+}
+
+#[test]
+#[should_panic(expected = "does not fit")]
+fn assign_block_wrong_ndim()
+{
+    let mut a = ArrayD::<f64>::zeros(vec![3, 4]);
+    a.assign_block(IxDyn(&[0, 0]), &ArrayD::ones(vec![2]));
+}
+
 #[test]
 fn assign_to()
 {
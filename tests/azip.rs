@@ -189,6 +189,45 @@ fn test_zip_collect_drop()
     }
 }
 
+#[test]
+fn test_zip_try_map_collect()
+{
+    use std::cell::Cell;
+
+    let a = Array::from_shape_fn((4, 5), |(i, j)| i * 10 + j);
+    let b = a.t();
+    let c = Zip::from(&b)
+        .try_map_collect(|&x| Ok::<_, ()>(x + 1))
+        .unwrap();
+    assert_eq!(c, &b + 1);
+    assert_eq!(c.strides(), b.strides());
+
+    struct Counted<'a>(&'a Cell<usize>);
+
+    impl Drop for Counted<'_>
+    {
+        fn drop(&mut self)
+        {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let mut visited = 0;
+    let result = Zip::from(&a).and(&b.t()).try_map_collect(|&x, _| {
+        visited += 1;
+        if x == 23 {
+            Err(x)
+        } else {
+            Ok(Counted(&drops))
+        }
+    });
+    assert_eq!(result.err(), Some(23));
+    // elements are visited in order up to the error, and those collected are dropped
+    assert_eq!(visited, 14);
+    assert_eq!(drops.get(), 13);
+}
+
 #[test]
 fn test_azip_syntax_trailing_comma()
 {
@@ -262,6 +301,22 @@ fn test_broadcast()
     assert_abs_diff_eq!(a, sum.broadcast((n, n)).unwrap(), epsilon = 1e-4);
 }
 
+#[test]
+fn test_broadcast_scalar()
+{
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| i * 4 + j);
+    let k = 2;
+    Zip::from(&mut a)
+        .and_broadcast(aview0(&k))
+        .for_each(|x, &k| *x *= k);
+    assert_eq!(a, Array::from_shape_fn((3, 4), |(i, j)| 2 * (i * 4 + j)));
+
+    // the broadcast scalar is never copied
+    let z = Zip::from(&a).and_broadcast(aview0(&k));
+    let ptrs = z.map_collect(|_, k| k as *const usize);
+    assert!(ptrs.iter().all(|&p| std::ptr::eq(p, &k)));
+}
+
 #[should_panic]
 #[test]
 fn test_zip_dim_mismatch_1()
@@ -98,3 +98,57 @@ fn test_broadcast_1d()
     println!("b2=\n{:?}", b2);
     assert_eq!(b0, b2);
 }
+
+#[test]
+fn test_try_broadcast()
+{
+    let a = Array::from_iter(0..6)
+        .into_shape_with_order((2, 1, 3))
+        .unwrap();
+    let b = a.try_broadcast((4, 2, 5, 3)).unwrap();
+    assert_eq!(b, a.broadcast((4, 2, 5, 3)).unwrap());
+
+    let err = a.try_broadcast((2, 5, 4)).unwrap_err();
+    assert_eq!(err.axis(), Some(Axis(2)));
+    assert_eq!(err.from_shape(), &[2, 1, 3]);
+    assert_eq!(err.to_shape(), &[2, 5, 4]);
+    assert!(err.to_string().contains("axis 2 has length 3"), "{}", err);
+
+    // axes are numbered as in the target shape
+    let err = a.try_broadcast((7, 3, 1, 3)).unwrap_err();
+    assert_eq!(err.axis(), Some(Axis(1)));
+    assert!(err.to_string().contains("axis 1 has length 2, which is not 1 or 3"), "{}", err);
+
+    let err = a.try_broadcast((1, 3)).unwrap_err();
+    assert_eq!(err.axis(), None);
+    assert!(err.to_string().contains("fewer axes"), "{}", err);
+
+    let err = a.try_broadcast(IxDyn(&[2, usize::MAX, 3])).unwrap_err();
+    assert_eq!(err.axis(), None);
+    assert_eq!(ndarray::ShapeError::from(err).kind(), ndarray::ErrorKind::Overflow);
+
+    let shape_err: ndarray::ShapeError = a.try_broadcast((2, 2, 2)).unwrap_err().into();
+    assert_eq!(shape_err.kind(), ndarray::ErrorKind::IncompatibleShape);
+}
+
+#[test]
+fn test_broadcast_tile()
+{
+    let a = array![[1, 2, 3]];
+    assert_eq!(a.broadcast_tile((2, 6)).unwrap(), array![[1, 2, 3, 1, 2, 3], [1, 2, 3, 1, 2, 3]]);
+    assert_eq!(a.broadcast_tile((1, 3)).unwrap(), a);
+    assert_eq!(a.broadcast_tile((3, 0)).unwrap().shape(), &[3, 0]);
+    assert!(a.broadcast_tile((2, 4)).is_none());
+
+    // any layout
+    let t = a.t();
+    assert_eq!(t.broadcast_tile((6, 2)).unwrap(), a.broadcast_tile((2, 6)).unwrap().reversed_axes());
+
+    let e = Array2::<i32>::zeros((0, 2));
+    assert_eq!(e.broadcast_tile((0, 4)).unwrap().shape(), &[0, 4]);
+    assert!(e.broadcast_tile((1, 4)).is_none());
+
+    let d = a.into_dyn();
+    assert!(d.broadcast_tile(IxDyn(&[2, 2, 3])).is_none());
+    assert!(d.broadcast_tile(IxDyn(&[usize::MAX, 3])).is_none());
+}
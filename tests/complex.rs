@@ -1,5 +1,5 @@
 use ndarray::Array;
-use ndarray::{arr1, arr2, Axis};
+use ndarray::{arr1, arr2, s, Axis};
 use num_complex::Complex;
 use num_traits::Num;
 
@@ -23,3 +23,37 @@ fn complex_mat_mul()
         arr1(&[c(1.5, 1.), c(2.5, 0.)])
     );
 }
+
+#[test]
+fn complex_re_im()
+{
+    let mut a = arr2(&[[c(1, 2), c(3, 4)], [c(5, 6), c(7, 8)]]);
+    assert_eq!(a.re(), arr2(&[[1, 3], [5, 7]]));
+    assert_eq!(a.im(), arr2(&[[2, 4], [6, 8]]));
+    assert_eq!(a.t().re(), arr2(&[[1, 5], [3, 7]]));
+
+    a.re_mut()[[0, 1]] = 0;
+    a.im_mut()[[1, 0]] = 0;
+    assert_eq!(a, arr2(&[[c(1, 2), c(0, 4)], [c(5, 0), c(7, 8)]]));
+
+    let mut col = a.column_mut(1);
+    col.im_mut().fill(-1);
+    assert_eq!(a.im(), arr2(&[[2, -1], [0, -1]]));
+}
+
+#[test]
+fn complex_conj()
+{
+    let a = arr2(&[[c(1., 2.), c(3., -4.)], [c(0., 0.), c(-5., 6.)]]);
+    let expected = arr2(&[[c(1., -2.), c(3., 4.)], [c(0., 0.), c(-5., -6.)]]);
+    assert_eq!(a.conj(), expected);
+
+    let mut b = a.clone();
+    b.conj_inplace();
+    assert_eq!(b, expected);
+    b.slice_mut(s![.., 1]).conj_inplace();
+    assert_eq!(b, arr2(&[[c(1., -2.), c(3., -4.)], [c(0., 0.), c(-5., 6.)]]));
+
+    assert_eq!(a.conj_t(), expected.t());
+    assert_eq!(a.conj_t().conj_t(), a);
+}
@@ -63,6 +63,45 @@ fn remove_axis()
         .unwrap();
 }
 
+#[test]
+#[should_panic(expected = "can not insert axis 3 in a dimension of 2 axes")]
+fn insert_axis_out_of_bounds()
+{
+    let _ = Dim([2, 3]).with_axis_inserted(Axis(3));
+}
+
+#[test]
+#[should_panic(expected = "can not remove axis 2 from a dimension of 2 axes")]
+fn remove_axis_out_of_bounds()
+{
+    let _ = Dim([2, 3]).with_axis_removed(Axis(2));
+}
+
+#[test]
+#[should_panic(expected = "can not remove axis 2 from a dimension of 2 axes")]
+fn remove_axis_out_of_bounds_dyn()
+{
+    let _ = Dim(vec![2, 3]).with_axis_removed(Axis(2));
+}
+
+#[test]
+fn insert_remove_axis_generic()
+{
+    fn stacked_shape<D: Dimension>(dim: &D, n: usize) -> D::Larger
+    {
+        let mut larger = dim.with_axis_inserted(Axis(0));
+        larger[0] = n;
+        larger
+    }
+
+    let shape = stacked_shape(&Dim([2, 3]), 4);
+    assert_eq!(shape, Dim([4, 2, 3]));
+    assert_eq!(shape.with_axis_removed(Axis(0)), Dim([2, 3]));
+    assert_eq!(stacked_shape(&IxDyn(&[2]), 4), IxDyn(&[4, 2]));
+    assert_eq!(IxDyn(&[2, 3]).with_axis_inserted(Axis(2)), IxDyn(&[2, 3, 1]));
+    assert_eq!(Dim([7]).with_axis_removed(Axis(0)), Dim(()));
+}
+
 #[test]
 #[allow(clippy::eq_op)]
 fn dyn_dimension()
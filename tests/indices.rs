@@ -1,6 +1,8 @@
 use ndarray::indices_of;
+use ndarray::indices_of_axis;
 use ndarray::prelude::*;
 use ndarray::Order;
+use ndarray::Zip;
 
 #[test]
 fn test_ixdyn_index_iterate()
@@ -23,3 +25,28 @@ fn test_ixdyn_index_iterate()
         assert_eq!(c, a.len());
     }
 }
+
+#[test]
+fn test_indices_of_axis()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    assert_eq!(indices_of_axis(&a, Axis(1)).into_iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+
+    Zip::from(a.axis_iter(Axis(2)))
+        .and(indices_of_axis(&a, Axis(2)))
+        .for_each(|view, k| assert_eq!(view, a.index_axis(Axis(2), k)));
+
+    let mut lanes = Array2::<i32>::zeros((2, 4));
+    Zip::from(&mut lanes)
+        .and(a.lanes(Axis(1)))
+        .for_each(|out, lane| {
+            Zip::from(lane)
+                .and(indices_of_axis(&a, Axis(1)))
+                .for_each(|&x, j| *out += x * j as i32)
+        });
+    assert_eq!(lanes, a.index_axis(Axis(1), 1).to_owned() + 2 * &a.index_axis(Axis(1), 2));
+
+    assert_eq!(indices_of_axis(&Array2::<u8>::zeros((3, 0)), Axis(1)).into_iter().count(), 0);
+}
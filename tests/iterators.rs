@@ -290,6 +290,47 @@ fn axis_iter()
         ]);
 }
 
+#[test]
+fn axes()
+{
+    let a = Array::<u8, _>::zeros((2, 3, 4).f());
+    let axes = a.axes();
+    assert_eq!(axes.len(), 3);
+    let triples: Vec<_> = axes.map(|ax| (ax.axis, ax.len, ax.stride)).collect();
+    assert_eq!(triples, vec![(Axis(0), 2, 1), (Axis(1), 3, 2), (Axis(2), 4, 6)]);
+
+    let mut axes = a.axes();
+    assert_eq!(axes.next_back().unwrap().axis, Axis(2));
+    assert_eq!(axes.len(), 2);
+
+    let v = a.slice(s![..;-1, .., 1]);
+    let triples: Vec<_> = v.axes().map(|ax| (ax.axis, ax.len, ax.stride)).collect();
+    assert_eq!(triples, vec![(Axis(0), 2, -1), (Axis(1), 3, 2)]);
+}
+
+#[test]
+fn row_col_iter()
+{
+    let a = Array::from_iter(0..6)
+        .into_shape_with_order((2, 3))
+        .unwrap();
+    assert_equal(a.row_iter(), vec![a.row(0), a.row(1)]);
+    assert_equal(a.row_iter().rev(), vec![a.row(1), a.row(0)]);
+    assert_equal(a.col_iter(), vec![a.column(0), a.column(1), a.column(2)]);
+    assert_equal(a.col_iter().rev(), vec![a.column(2), a.column(1), a.column(0)]);
+    assert_eq!(a.row_iter().len(), 2);
+    assert_eq!(a.col_iter().len(), 3);
+
+    let mut cols = a.col_iter();
+    cols.next();
+    assert_eq!(cols.len(), 2);
+    assert_eq!(cols.next_back().unwrap(), aview1(&[2, 5]));
+
+    let empty = Array2::<i32>::zeros((0, 3));
+    assert_eq!(empty.row_iter().len(), 0);
+    assert_eq!(empty.col_iter().len(), 3);
+}
+
 #[test]
 fn axis_iter_split_at()
 {
@@ -1084,3 +1125,24 @@ fn test_impl_iter_compiles()
 
     let _ = array_iter_non_empty_indices;
 }
+
+#[test]
+fn axis_iter_indexed()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let iter = a.axis_iter_indexed(axis);
+        assert_eq!(iter.len(), a.len_of(axis));
+        for (i, sub) in iter {
+            assert_eq!(sub, a.index_axis(axis, i));
+        }
+        for (i, sub) in a.axis_iter_indexed(axis).rev() {
+            assert_eq!(sub, a.index_axis(axis, i));
+        }
+    }
+    let indices: Vec<_> = a.axis_iter_indexed(Axis(2)).rev().map(|(i, _)| i).collect();
+    assert_eq!(indices, [3, 2, 1, 0]);
+}
@@ -58,3 +58,19 @@ fn test_ix0_add_broad()
     assert_eq!(b[0], 6.);
     assert_eq!(b[1], 7.);
 }
+
+#[test]
+fn test_ix0_as_scalar()
+{
+    let a = Array::from_elem((), 5);
+    assert_eq!(a.as_scalar(), &5);
+    assert_eq!(a.view().as_scalar(), &5);
+    assert_eq!(a.to_shared().as_scalar(), &5);
+
+    let b = Array::from_iter(0..6)
+        .into_shape_with_order((2, 3))
+        .unwrap();
+    let elt = b.slice(ndarray::s![1, 2]);
+    assert_eq!(elt.as_scalar(), &5);
+    assert!(std::ptr::eq(elt.as_scalar(), &b[[1, 2]]));
+}
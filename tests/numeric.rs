@@ -4,7 +4,7 @@
 )]
 
 use approx::assert_abs_diff_eq;
-use ndarray::{arr0, arr1, arr2, array, aview1, Array, Array1, Array2, Array3, Axis};
+use ndarray::{arr0, arr1, arr2, array, aview1, s, Array, Array1, Array2, Array3, Axis, ConvMode};
 use std::f64;
 
 #[test]
@@ -406,3 +406,552 @@ fn diff_panic_axis_out_of_bounds()
     let data = array![1, 2, 4, 7];
     data.diff(1, Axis(2));
 }
+
+#[test]
+fn gradient()
+{
+    let f = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * i + 3 * j + k * k * k) as f64);
+    let g = f.gradient(&[1., 0.5, 2.]);
+    assert_eq!(g.len(), 3);
+    assert_eq!(g[0].slice(s![.., 0, 0]), array![1., 2., 3.]);
+    assert!(g[1].iter().all(|&x| x == 6.));
+    assert_eq!(g[2].slice(s![0, 0, ..]), array![0.5, 2., 6.5, 14., 18.5]);
+    for (ax, g) in g.iter().enumerate() {
+        assert_eq!(g, &f.gradient_axis(Axis(ax), [1., 0.5, 2.][ax]));
+    }
+
+    // length 2 gives the one-sided difference at both ends
+    assert_eq!(array![[1, 5], [3, 2]].gradient_axis(Axis(1), 1), array![[4, 4], [-1, -1]]);
+    let t = f.t();
+    assert_eq!(t.gradient_axis(Axis(2), 1.), g[0].t());
+}
+
+#[test]
+#[should_panic(expected = "at least 2")]
+fn gradient_short_axis()
+{
+    Array2::<f64>::zeros((3, 1)).gradient(&[1., 1.]);
+}
+
+#[test]
+#[should_panic(expected = "spacings")]
+fn gradient_bad_spacing()
+{
+    Array2::<f64>::zeros((3, 3)).gradient(&[1.]);
+}
+
+#[test]
+fn convolve_axis_modes()
+{
+    let a = array![1, 2, 3, 4];
+    let v = array![1, 2];
+    assert_eq!(a.convolve_axis(Axis(0), v.view(), ConvMode::Full), array![1, 4, 7, 10, 8]);
+    assert_eq!(a.convolve_axis(Axis(0), v.view(), ConvMode::Same), array![1, 4, 7, 10]);
+    assert_eq!(a.convolve_axis(Axis(0), v.view(), ConvMode::Valid), array![4, 7, 10]);
+
+    // convolution is symmetric in its inputs, also when the kernel is the longer one
+    assert_eq!(v.convolve_axis(Axis(0), a.view(), ConvMode::Full), array![1, 4, 7, 10, 8]);
+    assert_eq!(v.convolve_axis(Axis(0), a.view(), ConvMode::Same), array![1, 4, 7, 10]);
+    assert_eq!(v.convolve_axis(Axis(0), a.view(), ConvMode::Valid), array![4, 7, 10]);
+
+    let ones = Array1::<i32>::ones(5);
+    let b = array![1, 2, 3];
+    assert_eq!(b.convolve_axis(Axis(0), ones.view(), ConvMode::Same), array![3, 6, 6, 6, 5]);
+}
+
+#[test]
+fn correlate_axis_modes()
+{
+    let a = array![1, 2, 3, 4];
+    let v = array![1, 2];
+    assert_eq!(a.correlate_axis(Axis(0), v.view(), ConvMode::Full), array![2, 5, 8, 11, 4]);
+    assert_eq!(a.correlate_axis(Axis(0), v.view(), ConvMode::Same), array![2, 5, 8, 11]);
+    assert_eq!(a.correlate_axis(Axis(0), v.view(), ConvMode::Valid), array![5, 8, 11]);
+
+    assert_eq!(v.correlate_axis(Axis(0), a.view(), ConvMode::Full), array![4, 11, 8, 5, 2]);
+    assert_eq!(v.correlate_axis(Axis(0), a.view(), ConvMode::Same), array![11, 8, 5, 2]);
+    assert_eq!(v.correlate_axis(Axis(0), a.view(), ConvMode::Valid), array![11, 8, 5]);
+}
+
+#[test]
+fn convolve_axis_2d()
+{
+    let a = array![[1, 10], [2, 20], [3, 30]];
+    let v = array![1, 1];
+    let expected = array![[1, 10], [3, 30], [5, 50], [3, 30]];
+    assert_eq!(a.convolve_axis(Axis(0), v.view(), ConvMode::Full), expected);
+    assert_eq!(a.t().convolve_axis(Axis(1), v.view(), ConvMode::Full), expected.t());
+
+    // negative strides along the axis of the lanes
+    let r = a.slice(ndarray::s![..;-1, ..]);
+    assert_eq!(r.correlate_axis(Axis(0), array![1, 0].view(), ConvMode::Valid), array![[3, 30], [2, 20]]);
+}
+
+#[test]
+#[should_panic]
+fn convolve_axis_empty_kernel()
+{
+    let a = array![1, 2, 3];
+    a.convolve_axis(Axis(0), Array1::zeros(0).view(), ConvMode::Full);
+}
+
+#[test]
+#[should_panic]
+fn convolve_axis_empty_axis()
+{
+    let a = Array2::<i32>::zeros((0, 3));
+    a.convolve_axis(Axis(0), array![1].view(), ConvMode::Full);
+}
+
+#[test]
+fn digitize()
+{
+    let bins = array![1, 3, 3, 7];
+    let x = Array::from_iter(0..9);
+    assert_eq!(x.digitize(bins.view(), false), array![0, 1, 1, 3, 3, 3, 3, 4, 4]);
+    assert_eq!(x.digitize(bins.view(), true), array![0, 0, 1, 1, 3, 3, 3, 3, 4]);
+
+    // shape is kept, bins may be strided
+    let x2 = Array::from_iter(0..8)
+        .into_shape_with_order((2, 4))
+        .unwrap();
+    let bins2 = array![7, 0, 3, 0, 1];
+    let d = x2.t().digitize(bins2.slice(ndarray::s![..;-2]), false);
+    assert_eq!(d, array![[0, 2], [1, 2], [1, 2], [2, 3]]);
+
+    let empty = Array1::<i32>::zeros(0);
+    assert_eq!(x.digitize(empty.view(), false), Array1::<usize>::zeros(9));
+    assert_eq!(array![f64::NAN].digitize(array![0.].view(), false), array![0]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn max_abs_diff()
+{
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let b = array![[1., 2.5, 3.], [3.5, 5., 6.25]];
+    assert_eq!(a.max_abs_diff(&b), Some(((0, 1), 0.5)));
+    assert_eq!(b.max_abs_diff(&a), Some(((0, 1), 0.5)));
+    assert_eq!(a.t().max_abs_diff(&b.t()), Some(((0, 1), 0.5)));
+
+    let c = array![[1., 2., f64::NAN], [f64::NAN, 5., 6.]];
+    assert!(matches!(a.max_abs_diff(&c), Some(((0, 2), d)) if d.is_nan()));
+
+    assert_eq!(a.max_abs_diff(&b.t()), None);
+    assert_eq!(Array2::<f64>::zeros((0, 3)).max_abs_diff(&Array2::zeros((0, 3))), None);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+#[cfg(feature = "std")]
+fn assert_arrays_close()
+{
+    use ndarray::assert_arrays_close;
+
+    let a = array![[1., 2.], [3., 4.]];
+    assert_arrays_close!(a, a.t().t(), 0.);
+    assert_arrays_close!(a.view(), array![[1., 2.], [3., 4. + 1e-9]], 1e-8,);
+    assert_arrays_close!(Array2::<f64>::zeros((0, 2)), Array2::zeros((0, 2)), 0.);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+#[cfg(feature = "std")]
+#[should_panic(expected = "at index (1, 0), left is 3.0 and right is 3.5, difference 0.5")]
+fn assert_arrays_close_fails()
+{
+    ndarray::assert_arrays_close!(array![[1., 2.], [3., 4.]], array![[1., 2.], [3.5, 4.]], 1e-3);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+#[cfg(feature = "std")]
+#[should_panic(expected = "shapes [2] and [1, 2] differ")]
+fn assert_arrays_close_shape()
+{
+    ndarray::assert_arrays_close!(array![1., 2.].into_dyn(), array![[1., 2.]].into_dyn(), 1e-3);
+}
+
+#[test]
+fn nonzero()
+{
+    let a = array![[[0, 1], [2, 0]], [[0, 0], [0, 3]]];
+    assert_eq!(a.nonzero(), array![[0, 0, 1], [0, 1, 0], [1, 1, 1]]);
+    for row in a.nonzero().rows() {
+        assert_ne!(a[[row[0], row[1], row[2]]], 0);
+    }
+    assert_eq!(Array3::<f64>::zeros((2, 0, 3)).nonzero().shape(), &[0, 3]);
+    assert_eq!(arr0(1.).nonzero().shape(), &[1, 0]);
+    assert_eq!(arr0(0.).nonzero().shape(), &[0, 0]);
+    assert_eq!(a.clone().into_dyn().nonzero(), a.nonzero());
+}
+
+#[test]
+fn nonzero_1d()
+{
+    let a = array![0, 4, 0, 0, 1];
+    assert_eq!(a.nonzero_1d(), array![1, 4]);
+    assert_eq!(a.slice(s![..;-1]).nonzero_1d(), array![0, 3]);
+    assert_eq!(a.nonzero().column(0), a.nonzero_1d());
+    assert_eq!(Array1::<i32>::zeros(3).nonzero_1d().len(), 0);
+}
+
+#[test]
+fn rank_axis_methods()
+{
+    use ndarray::RankMethod;
+
+    // scipy.stats.rankdata([0, 2, 3, 2], method=...)
+    let a = array![0, 2, 3, 2];
+    assert_eq!(a.rank_axis(Axis(0), RankMethod::Average), array![1., 2.5, 4., 2.5]);
+    assert_eq!(a.rank_axis(Axis(0), RankMethod::Min), array![1., 2., 4., 2.]);
+    assert_eq!(a.rank_axis(Axis(0), RankMethod::Max), array![1., 3., 4., 3.]);
+    assert_eq!(a.rank_axis(Axis(0), RankMethod::Dense), array![1., 2., 3., 2.]);
+    assert_eq!(a.rank_axis(Axis(0), RankMethod::Ordinal), array![1., 2., 4., 3.]);
+
+    // scipy.stats.rankdata([[0, 2], [3, 2], [1, 2]], axis=0)
+    let b = array![[0, 2], [3, 2], [1, 2]];
+    assert_eq!(b.rank_axis(Axis(0), RankMethod::Average), array![[1., 2.], [3., 2.], [2., 2.]]);
+    assert_eq!(b.rank_axis(Axis(0), RankMethod::Ordinal), array![[1., 1.], [3., 2.], [2., 3.]]);
+    assert_eq!(b.t().rank_axis(Axis(1), RankMethod::Max), array![[1., 3.], [3., 3.], [2., 3.]].reversed_axes());
+
+    let e = Array2::<f64>::zeros((0, 3));
+    assert_eq!(e.rank_axis(Axis(0), RankMethod::Average).shape(), &[0, 3]);
+}
+
+#[test]
+fn rank_axis_nan_last()
+{
+    use ndarray::RankMethod;
+
+    // long enough that the sort checks its comparator for a total order
+    let is_nan = |i: usize| i % 8 == 3;
+    let a = Array::from_shape_fn(64, |i| if is_nan(i) { f64::NAN } else { (64 - i) as f64 });
+    let average = a.rank_axis(Axis(0), RankMethod::Average);
+    let ordinal = a.rank_axis(Axis(0), RankMethod::Ordinal);
+    let mut nans_seen = 0;
+    for i in 0..64 {
+        if is_nan(i) {
+            nans_seen += 1;
+            assert_eq!(average[i], 60.5);
+            assert_eq!(ordinal[i], (56 + nans_seen) as f64);
+        } else {
+            let rank = 1 + (i + 1..64).filter(|&j| !is_nan(j)).count();
+            assert_eq!(average[i], rank as f64);
+            assert_eq!(ordinal[i], rank as f64);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn is_all_nan_is_all_infinite()
+{
+    // `is_all_*` used to return `true` when no element was NaN (infinite)
+    let finite = array![1., 2.];
+    assert!(!finite.is_all_nan() && !finite.is_any_nan());
+    assert!(!finite.is_all_infinite() && !finite.is_any_infinite());
+
+    let mixed = array![1., f64::NAN, f64::INFINITY];
+    assert!(!mixed.is_all_nan() && mixed.is_any_nan());
+    assert!(!mixed.is_all_infinite() && mixed.is_any_infinite());
+
+    assert!(array![f64::NAN, f64::NAN].is_all_nan());
+    assert!(array![f64::INFINITY, -f64::INFINITY].is_all_infinite());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn float_masks()
+{
+    let a = array![[1., f64::NAN], [f64::INFINITY, -f64::INFINITY]];
+    assert_eq!(a.is_nan(), array![[false, true], [false, false]]);
+    assert_eq!(a.is_infinite(), array![[false, false], [true, true]]);
+    assert_eq!(a.is_finite(), array![[true, false], [false, false]]);
+
+    assert!(a.is_any_nan() && !a.is_all_nan());
+    assert!(a.is_any_infinite() && !a.is_all_infinite());
+    assert!(a.is_any_finite() && !a.is_all_finite());
+
+    let nan = Array1::from_elem(3, f32::NAN);
+    assert!(nan.is_all_nan() && nan.is_any_nan());
+    assert!(!nan.is_any_finite());
+    let ones = Array1::<f32>::ones(3);
+    assert!(ones.is_all_finite() && !ones.is_any_nan() && !ones.is_any_infinite());
+
+    let empty = Array1::<f64>::zeros(0);
+    assert!(empty.is_all_nan() && !empty.is_any_nan());
+}
+
+#[test]
+fn clip_counting()
+{
+    let a = array![[-2, 0, 5], [9, 3, 4]];
+    let (clipped, count) = a.clip_counting(0, 4);
+    assert_eq!(clipped, a.clamp(0, 4));
+    assert_eq!(count, 3);
+
+    let (clipped, count) = a.t().clip_counting(-5, 10);
+    assert_eq!(clipped, a.t());
+    assert_eq!(count, 0);
+
+    let b = array![f64::NAN, 2.];
+    let (clipped, count) = b.clip_counting(0., 1.);
+    assert!(clipped[0].is_nan());
+    assert_eq!(clipped[1], 1.);
+    assert_eq!(count, 1);
+}
+
+#[test]
+#[should_panic]
+fn clip_counting_bad_bounds()
+{
+    array![1, 2].clip_counting(3, 2);
+}
+
+#[test]
+fn clamp_axis()
+{
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as i32);
+    let mins = array![[2, 0, 5, 9], [13, 0, 0, 0]];
+    let maxs = array![[4, 20, 6, 10], [14, 1, 30, 17]];
+    let mut c = a.clone();
+    c.clamp_axis(Axis(1), mins.view(), maxs.view());
+    for (j, sub) in c.axis_iter(Axis(1)).enumerate() {
+        for ((i, k), &x) in sub.indexed_iter() {
+            assert_eq!(x, a[[i, j, k]].clamp(mins[[i, k]], maxs[[i, k]]));
+        }
+    }
+
+    // along the last axis of a non-contiguous view, with equal bounds
+    let mut c = a.clone();
+    let bounds = Array2::from_elem((2, 2), 7);
+    c.slice_mut(s![.., ..;2, ..])
+        .clamp_axis(Axis(2), bounds.view(), bounds.view());
+    assert!(c.slice(s![.., ..;2, ..]).iter().all(|&x| x == 7));
+    assert_eq!(c.slice(s![.., 1, ..]), a.slice(s![.., 1, ..]));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "every min must be less than or equal to the corresponding max")]
+fn clamp_axis_bad_bounds()
+{
+    // checked even if the axis is empty, so that no bounds are used
+    let mut a = Array2::<i32>::zeros((0, 3));
+    a.clamp_axis(Axis(0), array![0, 2, 0].view(), array![1, 1, 1].view());
+}
+
+#[test]
+#[should_panic(expected = "do not match the reduced shape")]
+fn clamp_axis_bad_shape()
+{
+    let mut a = Array2::<i32>::zeros((2, 3));
+    a.clamp_axis(Axis(0), array![0, 0].view(), array![1, 1].view());
+}
+
+#[test]
+fn minmax()
+{
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| ((i * 7 + j * 11 + k * 13) % 17) as i32 - 8);
+    let expected = (*a.iter().min().unwrap(), *a.iter().max().unwrap());
+    assert_eq!(a.minmax(), Some(expected));
+    assert_eq!(a.t().minmax(), Some(expected));
+    assert_eq!(Array1::<f64>::zeros(0).minmax(), None);
+
+    for ax in 0..3 {
+        let (min, max) = a.minmax_axis(Axis(ax));
+        assert_eq!(min, a.map_axis(Axis(ax), |lane| *lane.iter().min().unwrap()));
+        assert_eq!(max, a.map_axis(Axis(ax), |lane| *lane.iter().max().unwrap()));
+        assert_eq!(a.t().minmax_axis(Axis(2 - ax)), (min.t().to_owned(), max.t().to_owned()));
+    }
+
+    let x = array![[1.5, -2.], [0.5, 4.]];
+    assert_eq!(x.minmax_axis(Axis(0)), (array![0.5, -2.], array![1.5, 4.]));
+    assert_eq!(Array2::<u8>::zeros((1, 0)).minmax_axis(Axis(0)).0.shape(), &[0]);
+}
+
+#[test]
+#[should_panic(expected = "has length zero")]
+fn minmax_axis_empty()
+{
+    Array2::<u8>::zeros((1, 0)).minmax_axis(Axis(1));
+}
+
+#[test]
+fn axis_reductions_keep()
+{
+    let a = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    for ax in 0..3 {
+        let axis = Axis(ax);
+        let sum = a.sum_axis_keep(axis);
+        assert_eq!(sum.len_of(axis), 1);
+        assert_eq!(sum.index_axis(axis, 0), a.sum_axis(axis));
+        let (min, max) = a.minmax_axis_keep(axis);
+        let (min_r, max_r) = a.minmax_axis(axis);
+        assert_eq!(min.index_axis(axis, 0), min_r);
+        assert_eq!(max.index_axis(axis, 0), max_r);
+        assert!((&a - &min).iter().all(|&x| x >= 0));
+    }
+
+    let x = array![[1., 2., 6.], [-4., 0., 1.]];
+    let centered = &x - &x.mean_axis_keep(Axis(0)).unwrap();
+    assert_eq!(centered, array![[2.5, 1., 2.5], [-2.5, -1., -2.5]]);
+    assert_eq!(centered.sum_axis_keep(Axis(0)), array![[0., 0., 0.]]);
+    assert!(Array2::<f64>::zeros((0, 3)).mean_axis_keep(Axis(0)).is_none());
+
+    let d = x.clone().into_dyn();
+    assert_eq!(d.sum_axis_keep(Axis(1)), array![[9.], [-3.]].into_dyn());
+}
+
+#[test]
+fn cummax_cummin_axis()
+{
+    let a = array![[4, 1, 7], [2, 9, 0], [5, 3, 6]];
+    assert_eq!(a.cummax_axis(Axis(0)), array![[4, 1, 7], [4, 9, 7], [5, 9, 7]]);
+    assert_eq!(a.cummin_axis(Axis(0)), array![[4, 1, 7], [2, 1, 0], [2, 1, 0]]);
+    assert_eq!(a.t().cummax_axis(Axis(1)), a.cummax_axis(Axis(0)).t());
+    assert_eq!(a.slice(s![..;-1, ..]).cummin_axis(Axis(1)), array![[5, 3, 3], [2, 2, 0], [4, 1, 1]]);
+    assert_eq!(Array2::<u8>::zeros((0, 3)).cummax_axis(Axis(0)).shape(), &[0, 3]);
+
+    let x = array![f64::NAN, 2., 1.];
+    assert!(x.cummin_axis(Axis(0)).iter().all(|v| v.is_nan()));
+    let x = array![2., 1., f64::NAN, 0.];
+    let m = x.cummin_axis(Axis(0));
+    assert_eq!(m.slice(s![..2]), array![2., 1.]);
+    assert!(m.slice(s![2..]).iter().all(|v| v.is_nan()));
+}
+
+#[test]
+#[cfg(feature = "approx")]
+#[cfg(feature = "std")]
+fn interp_axis()
+{
+    let xp = array![0., 1., 1., 4.];
+    let a = Array::from_shape_fn((4, 2, 3), |(i, j, k)| xp[i] * (j + 1) as f64 + k as f64);
+    let x = array![-2., 0., 0.5, 1., 2.5, 4., 9.];
+    let r = a.interp_axis(Axis(0), x.view(), xp.view());
+    assert_eq!(r.shape(), &[7, 2, 3]);
+    let xc = x.mapv(|x: f64| x.max(0.).min(4.));
+    let expected = Array::from_shape_fn((7, 2, 3), |(i, j, k)| xc[i] * (j + 1) as f64 + k as f64);
+    assert_abs_diff_eq!(r, expected, epsilon = 1e-12);
+
+    // repeated coordinates take the last value
+    let fp = array![1., 2., 3.];
+    let r = fp.interp_axis(Axis(0), array![1., 0.5, 1.5].view(), array![0., 1., 1.].view());
+    assert_abs_diff_eq!(r, array![3., 1.5, 3.]);
+
+    let r = fp.interp_axis(Axis(0), array![f64::NAN].view(), array![0., 1., 2.].view());
+    assert!(r[0].is_nan());
+    let empty = Array2::<f64>::zeros((0, 2));
+    assert_eq!(empty.interp_axis(Axis(0), array![].view(), array![].view()).shape(), &[0, 2]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic(expected = "sorted")]
+fn interp_axis_unsorted()
+{
+    array![1., 2., 3.].interp_axis(Axis(0), array![0.5].view(), array![0., 2., 1.].view());
+}
+
+#[test]
+#[cfg(feature = "approx")]
+#[cfg(feature = "std")]
+fn rescale_axis()
+{
+    let a = array![[0.1, 0.7, 0.3, 0.7], [-2., 8., 3., 0.5]];
+    for ax in 0..2 {
+        let r = a.rescale_axis(Axis(ax), 0.1, 0.7);
+        for lane in r.lanes(Axis(ax)) {
+            assert_eq!(lane.minmax(), Some((0.1, 0.7)));
+        }
+    }
+    let r = a.rescale_axis(Axis(1), 0., 10.);
+    assert_abs_diff_eq!(r, array![[0., 10., 10. / 3., 10.], [0., 10., 5., 2.5]], epsilon = 1e-12);
+
+    // reversed range
+    assert_eq!(a.rescale_axis(Axis(1), 1., 0.).row(1), array![1., 0., 0.5, 0.75]);
+    // constant lanes
+    assert_eq!(Array2::from_elem((2, 3), 4.).rescale_axis(Axis(0), 1., 2.), Array2::ones((2, 3)));
+}
+
+#[test]
+#[cfg(feature = "approx")]
+#[cfg(feature = "std")]
+fn cov_corrcoef()
+{
+    let x: Array2<f64> = array![[0.5, 1.5, -2., 4.], [3., 1., 0.25, -1.], [1., 3., -4., 8.]];
+    let cov = x.cov(1.);
+    for i in 0..3 {
+        assert_abs_diff_eq!(cov[[i, i]], x.row(i).var(1.), epsilon = 1e-12);
+        for j in 0..3 {
+            let (xi, xj) = (x.row(i), x.row(j));
+            let expected = (&xi - xi.mean().unwrap()).dot(&(&xj - xj.mean().unwrap())) / 3.;
+            assert_abs_diff_eq!(cov[[i, j]], expected, epsilon = 1e-12);
+        }
+    }
+    assert_abs_diff_eq!(x.cov(0.), &cov * 0.75, epsilon = 1e-12);
+    // non-standard layout
+    assert_abs_diff_eq!(x.t().to_owned().t().cov(1.), cov, epsilon = 1e-12);
+
+    let corr = x.corrcoef();
+    assert_abs_diff_eq!(corr.diag(), array![1., 1., 1.], epsilon = 1e-12);
+    assert_abs_diff_eq!(corr[[0, 2]], 1., epsilon = 1e-12);
+    assert!(corr.iter().all(|r| (-1. ..=1.).contains(r)));
+    assert_abs_diff_eq!(corr[[0, 1]], cov[[0, 1]] / (cov[[0, 0]] * cov[[1, 1]]).sqrt(), epsilon = 1e-12);
+    assert_eq!(corr, corr.t());
+
+    // zero variance and single observations
+    let c = array![[1., 2., 3.], [5., 5., 5f64]].corrcoef();
+    assert_abs_diff_eq!(c[[0, 0]], 1., epsilon = 1e-12);
+    assert!(c[[0, 1]].is_nan() && c[[1, 0]].is_nan() && c[[1, 1]].is_nan());
+    let single = array![[1.], [2f64]];
+    assert_eq!(single.cov(0.), Array2::zeros((2, 2)));
+    assert!(single.cov(1.).iter().all(|c| c.is_nan()));
+}
+
+#[test]
+#[should_panic(expected = "ddof")]
+#[cfg(feature = "std")]
+fn cov_ddof_too_large()
+{
+    array![[1., 2.], [3., 4.]].cov(3.);
+}
+
+#[test]
+fn sum_mean_axes()
+{
+    let a = Array::from_shape_fn((2, 3, 4, 5), |(i, j, k, l)| (i * 1000 + j * 100 + k * 10 + l) as f64);
+    let by_chaining = a.sum_axis(Axis(3)).sum_axis(Axis(1));
+    assert_eq!(a.sum_axes(&[Axis(1), Axis(3)]), by_chaining.clone().into_dyn());
+    assert_eq!(a.sum_axes(&[Axis(3), Axis(1)]), by_chaining.into_dyn());
+    assert_eq!(a.sum_axes(&[]), a.clone().into_dyn());
+    assert_eq!(a.t().sum_axes(&[Axis(0), Axis(2)]), a.sum_axes(&[Axis(1), Axis(3)]).t());
+    assert_eq!(a.sum_axes(&[Axis(0), Axis(1), Axis(2), Axis(3)])[[]], a.sum());
+
+    let mean = a.mean_axes(&[Axis(0), Axis(2)]).unwrap();
+    assert_eq!(mean.shape(), &[3, 5]);
+    assert_eq!(mean, a.mean_axis(Axis(2)).unwrap().mean_axis(Axis(0)).unwrap().into_dyn());
+
+    let empty = Array3::<f64>::zeros((2, 0, 3));
+    assert_eq!(empty.sum_axes(&[Axis(0), Axis(1)]), Array1::zeros(3).into_dyn());
+    assert_eq!(empty.mean_axes(&[Axis(0), Axis(1)]), None);
+    assert_eq!(empty.mean_axes(&[Axis(0), Axis(2)]).unwrap().shape(), &[0]);
+}
+
+#[test]
+#[should_panic(expected = "listed more than once")]
+fn sum_axes_repeated()
+{
+    Array3::<f64>::zeros((2, 3, 4)).sum_axes(&[Axis(1), Axis(0), Axis(1)]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn sum_axes_oob()
+{
+    Array3::<f64>::zeros((2, 3, 4)).sum_axes(&[Axis(3)]);
+}
@@ -4,6 +4,7 @@
 #![cfg(feature = "std")]
 use ndarray::linalg::general_mat_mul;
 use ndarray::linalg::kron;
+use ndarray::linalg::{mat, Matrix};
 use ndarray::prelude::*;
 #[cfg(feature = "approx")]
 use ndarray::Order;
@@ -83,6 +84,41 @@ fn operations()
     test_oper("neg", &[1.0, 2.0, 3.0, 4.0], &[1.0, 1.0, 2.0, 3.0], &[-1.0, -2.0, -3.0, -4.0]);
 }
 
+#[test]
+fn cow_view_operations()
+{
+    let a = arr2(&[[1., 2.], [3., 4.]]);
+    let b = arr2(&[[0., 1.], [2., 3.]]);
+    let aa = CowArray::from(a.view());
+    let bb = CowArray::from(&b);
+
+    assert_eq!(&aa + &bb, arr2(&[[1., 3.], [5., 7.]]));
+    assert_eq!(&aa - &b, arr2(&[[1., 1.], [1., 1.]]));
+
+    let sum = aa.clone() + &bb;
+    assert!(sum.is_owned());
+    assert_eq!(sum, arr2(&[[1., 3.], [5., 7.]]));
+
+    // Mutation converts the view to owned data once and leaves `a` alone
+    let mut cc = aa.clone();
+    assert!(cc.is_view());
+    cc += &bb;
+    assert!(cc.is_owned());
+    let ptr = cc.as_ptr();
+    cc *= &bb;
+    cc -= 1.;
+    assert_eq!(cc.as_ptr(), ptr);
+    assert_eq!(cc, arr2(&[[-1., 2.], [9., 20.]]));
+    assert_eq!(a, arr2(&[[1., 2.], [3., 4.]]));
+
+    // Broadcasting
+    let row = CowArray::from(arr1(&[10., 20.]));
+    assert_eq!(&aa + &row, arr2(&[[11., 22.], [13., 24.]]));
+    let mut dd = aa.clone();
+    dd += &row;
+    assert_eq!(dd, arr2(&[[11., 22.], [13., 24.]]));
+}
+
 #[test]
 fn scalar_operations()
 {
@@ -869,3 +905,270 @@ fn kron_i64()
     let r = arr2(&[[0, 1, 0, 0], [1, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 1], [0, 0, 1, 0]]);
     assert_eq!(kron(&a, &b), r);
 }
+
+#[test]
+fn matrix_mul_operator()
+{
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    let b = array![[1, 0], [0, 1], [1, 1]];
+
+    let c = mat(&a) * mat(&b);
+    assert_eq!(c.dim(), (2, 2));
+    assert_eq!(c.into_inner(), a.dot(&b));
+
+    // chained products and owned matrices
+    let aba = mat(&a) * mat(&b) * Matrix(a.clone());
+    assert_eq!(*aba, a.dot(&b).dot(&a));
+
+    // views with non-standard layout
+    assert_eq!(mat(&a.t()) * mat(&b.t()), Matrix(a.t().dot(&b.t())));
+    assert_eq!(&a * &a, array![[1, 4, 9], [16, 25, 36]]);
+}
+
+#[test]
+#[should_panic]
+fn matrix_mul_operator_shape_mismatch()
+{
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    let _ = mat(&a) * mat(&a);
+}
+
+#[test]
+fn matrix_power()
+{
+    let a = array![[1, 2, 0], [0, 1, 3], [1, 0, 1]];
+    let mut expected = Array2::<i64>::eye(3);
+    for n in 0..12 {
+        assert_eq!(a.matrix_power(n), expected, "power {}", n);
+        expected = expected.dot(&a);
+    }
+
+    // non-standard layout
+    assert_eq!(a.t().matrix_power(5), a.matrix_power(5).t());
+
+    let empty = Array2::<f64>::zeros((0, 0));
+    assert_eq!(empty.matrix_power(3).dim(), (0, 0));
+}
+
+#[test]
+#[should_panic]
+fn matrix_power_not_square()
+{
+    let a = array![[1, 2, 3], [4, 5, 6]];
+    a.matrix_power(2);
+}
+
+#[test]
+fn ger_rank1_update()
+{
+    let x = array![1, 2, 3];
+    let y = array![1, -1];
+    let mut a = array![[1, 1], [1, 1], [1, 1]];
+    a.ger(2, &x, &y);
+    assert_eq!(a, array![[3, -1], [5, -3], [7, -5]]);
+
+    // f-order destination and strided, reversed vectors
+    let mut b = Array2::<f64>::zeros((3, 2).f());
+    let xs = array![1., 0., 2., 0., 3.];
+    let ys = array![1., 2.];
+    b.ger(1., &xs.slice(s![..;2]), &ys.slice(s![..;-1]));
+    assert_eq!(b, array![[2., 1.], [4., 2.], [6., 3.]]);
+}
+
+#[test]
+#[should_panic]
+fn ger_shape_mismatch()
+{
+    let mut a = Array2::<f64>::zeros((3, 2));
+    a.ger(1., &array![1., 2.], &array![1., 2.]);
+}
+
+#[test]
+fn batched_trace()
+{
+    let a = Array::from_iter(0..27)
+        .into_shape_with_order((3, 3, 3))
+        .unwrap();
+    let expected: Array1<i32> = a
+        .outer_iter()
+        .map(|m| m[[0, 0]] + m[[1, 1]] + m[[2, 2]])
+        .collect();
+    assert_eq!(a.batched_trace(), expected);
+
+    // the matrices are transposed, which keeps the trace
+    assert_eq!(a.view().permuted_axes([0, 2, 1]).batched_trace(), expected);
+
+    let empty = Array3::<f64>::zeros((2, 0, 0));
+    assert_eq!(empty.batched_trace(), array![0., 0.]);
+}
+
+#[test]
+#[should_panic]
+fn batched_trace_not_square()
+{
+    let a = Array3::<f64>::zeros((2, 3, 2));
+    a.batched_trace();
+}
+
+#[test]
+fn tensordot()
+{
+    let x = Array::from_iter(0..24)
+        .into_shape_with_order((2, 3, 4))
+        .unwrap();
+    let y = Array::from_iter(0..30)
+        .into_shape_with_order((3, 5, 2))
+        .unwrap();
+
+    let z = x.tensordot(&y, &[-3, 1], &[2, -3]);
+    assert_eq!(z.shape(), &[4, 5]);
+    let mut expected = Array2::zeros((4, 5));
+    for ((i, j), e) in expected.indexed_iter_mut() {
+        for a in 0..2 {
+            for b in 0..3 {
+                *e += x[[a, b, i]] * y[[b, j, a]];
+            }
+        }
+    }
+    assert_eq!(z, expected.into_dyn());
+
+    // layout of the operands does not matter
+    let xt = x.t();
+    let yr = y.slice(s![.., .., ..;-1]);
+    let zt = xt.tensordot(&yr, &[2, 1], &[-1, 0]);
+    assert_eq!(zt, x.tensordot(&yr.to_owned(), &[0, 1], &[2, 0]));
+
+    // no contraction is the outer product
+    let a = array![1, 2];
+    let b = array![1, 10, 100];
+    assert_eq!(a.tensordot(&b, &[], &[]), array![[1, 10, 100], [2, 20, 200]].into_dyn());
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn tensordot_axis_oob()
+{
+    let a = Array2::<f64>::zeros((2, 3));
+    a.tensordot(&a, &[-3], &[0]);
+}
+
+#[test]
+#[should_panic(expected = "is repeated")]
+fn tensordot_axis_repeated()
+{
+    let a = Array2::<f64>::zeros((3, 3));
+    a.tensordot(&a, &[0, -2], &[0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "can not be contracted")]
+fn tensordot_length_mismatch()
+{
+    let a = Array2::<f64>::zeros((2, 3));
+    a.tensordot(&a, &[0], &[1]);
+}
+
+#[test]
+#[should_panic(expected = "can not be paired")]
+fn tensordot_axes_count_mismatch()
+{
+    let a = Array2::<f64>::zeros((2, 2));
+    a.tensordot(&a, &[0], &[0, 1]);
+}
+
+#[test]
+fn matmul_inner()
+{
+    let a: Array2<f64> = ArrayBuilder::new((4, 5)).build();
+    let b: Array2<f64> = ArrayBuilder::new((5, 3)).build();
+    assert_eq!(a.matmul(&b), a.dot(&b));
+    assert_eq!(a.t().matmul(&a), a.t().dot(&a));
+    assert_eq!(a.slice(s![.., ..0]).matmul(&b.slice(s![..0, ..])), Array2::zeros((4, 3)));
+
+    let x = arr1(&[1., 2., 3.]);
+    assert_eq!(x.inner(&arr1(&[4., 5., 6.])), 32.);
+    assert_eq!(x.slice(s![..;-1]).inner(&x), 10.);
+    assert_eq!(Array1::<f64>::zeros(0).inner(&Array1::zeros(0)), 0.);
+}
+
+#[test]
+#[should_panic(expected = "not compatible for matrix multiplication")]
+fn matmul_shape_mismatch()
+{
+    let a = Array2::<f64>::zeros((2, 3));
+    a.matmul(&a);
+}
+
+#[test]
+#[should_panic]
+fn inner_shape_mismatch()
+{
+    arr1(&[1., 2.]).inner(&arr1(&[1., 2., 3.]));
+}
+
+#[test]
+fn outer()
+{
+    let a = arr1(&[1., 2., 3.]);
+    let b = arr1(&[4., 5.]);
+    let expected = Array2::from_shape_fn((3, 2), |(i, j)| a[i] * b[j]);
+    assert_eq!(a.outer(&b), expected);
+    assert_eq!(a.slice(s![..;-1]).outer(&b), expected.slice(s![..;-1, ..]));
+    assert_eq!(a.outer(&Array1::zeros(0)).shape(), &[3, 0]);
+
+    let a = Array::linspace(0., 1., 20);
+    assert_eq!(a.outer(&a), a.view().insert_axis(Axis(1)).dot(&a.view().insert_axis(Axis(0))));
+}
+
+#[test]
+fn bmm()
+{
+    let a: Array3<f64> = ArrayBuilder::new((3, 4, 5)).build();
+    let b: Array3<f64> = ArrayBuilder::new((3, 5, 2)).build();
+    let c = a.bmm(&b);
+    assert_eq!(c.dim(), (3, 4, 2));
+    for i in 0..3 {
+        assert_eq!(c.index_axis(Axis(0), i), a.index_axis(Axis(0), i).dot(&b.index_axis(Axis(0), i)));
+    }
+
+    let bt = b.view().permuted_axes([0, 2, 1]).to_owned();
+    assert_eq!(a.bmm(&bt.view().permuted_axes([0, 2, 1])), c);
+    assert_eq!(a.slice(s![..0, .., ..]).bmm(&b.slice(s![..0, .., ..])).dim(), (0, 4, 2));
+    assert_eq!(a.slice(s![.., .., ..0]).bmm(&b.slice(s![.., ..0, ..])), Array3::zeros((3, 4, 2)));
+}
+
+#[test]
+#[should_panic(expected = "not compatible for batched matrix multiplication")]
+fn bmm_batch_mismatch()
+{
+    let a = Array3::<f64>::zeros((2, 3, 3));
+    a.bmm(&Array3::zeros((3, 3, 3)));
+}
+
+#[test]
+#[should_panic(expected = "not compatible for matrix multiplication")]
+fn bmm_shape_mismatch()
+{
+    let a = Array3::<f64>::zeros((2, 3, 4));
+    a.bmm(&Array3::zeros((2, 3, 4)));
+}
+
+#[test]
+fn block_diag()
+{
+    use ndarray::linalg::block_diag;
+
+    let a = Array2::from_shape_fn((2, 3), |(i, j)| 1 + i * 3 + j);
+    let b = arr2(&[[7], [8], [9]]);
+    let e = Array2::<usize>::zeros((1, 0));
+    let d = block_diag(&[a.view(), e.view(), b.t(), b.view()]);
+    assert_eq!(d.dim(), (2 + 1 + 1 + 3, 3 + 0 + 3 + 1));
+    assert_eq!(d.slice(s![..2, ..3]), a);
+    assert_eq!(d.slice(s![3..4, 3..6]), b.t());
+    assert_eq!(d.slice(s![4.., 6..]), b);
+    assert_eq!(d.sum(), a.sum() + 2 * b.sum());
+    assert_eq!(d.row(2), Array1::zeros(7));
+
+    assert_eq!(block_diag::<f64>(&[]).dim(), (0, 0));
+    assert_eq!(block_diag(&[a.view()]), a);
+}
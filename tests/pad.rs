@@ -0,0 +1,71 @@
+use ndarray::prelude::*;
+use ndarray::PadMode;
+
+#[test]
+fn pad_axis_modes()
+{
+    let a = array![1, 2, 3];
+    let pad = |mode| a.pad_axis(Axis(0), 2, 2, mode);
+    assert_eq!(pad(PadMode::Constant(0)), array![0, 0, 1, 2, 3, 0, 0]);
+    assert_eq!(pad(PadMode::Edge), array![1, 1, 1, 2, 3, 3, 3]);
+    assert_eq!(pad(PadMode::Reflect), array![3, 2, 1, 2, 3, 2, 1]);
+    assert_eq!(pad(PadMode::Symmetric), array![2, 1, 1, 2, 3, 3, 2]);
+    assert_eq!(pad(PadMode::Wrap), array![2, 3, 1, 2, 3, 1, 2]);
+}
+
+#[test]
+fn pad_axis_wider_than_axis()
+{
+    let a = array![1, 2, 3];
+    let pad = |mode| a.pad_axis(Axis(0), 5, 4, mode);
+    assert_eq!(pad(PadMode::Edge), array![1, 1, 1, 1, 1, 1, 2, 3, 3, 3, 3, 3]);
+    assert_eq!(pad(PadMode::Reflect), array![2, 1, 2, 3, 2, 1, 2, 3, 2, 1, 2, 3]);
+    assert_eq!(pad(PadMode::Symmetric), array![2, 3, 3, 2, 1, 1, 2, 3, 3, 2, 1, 1]);
+    assert_eq!(pad(PadMode::Wrap), array![2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1]);
+
+    let one = array![7];
+    assert_eq!(one.pad_axis(Axis(0), 2, 1, PadMode::Reflect), array![7, 7, 7, 7]);
+}
+
+#[test]
+fn pad_axis_2d()
+{
+    let a = array![[1, 2], [3, 4]];
+    assert_eq!(a.pad_axis(Axis(1), 1, 0, PadMode::Wrap), array![[2, 1, 2], [4, 3, 4]]);
+    assert_eq!(a.t().pad_axis(Axis(0), 0, 1, PadMode::Constant(9)), array![[1, 3], [2, 4], [9, 9]]);
+    assert_eq!(a.pad_axis(Axis(0), 0, 0, PadMode::Edge), a);
+}
+
+#[test]
+fn pad_axis_empty()
+{
+    let a = Array2::<i32>::zeros((0, 3));
+    assert_eq!(a.pad_axis(Axis(0), 1, 1, PadMode::Constant(5)), Array2::from_elem((2, 3), 5));
+    assert_eq!(a.pad_axis(Axis(0), 0, 0, PadMode::Reflect).shape(), &[0, 3]);
+    assert_eq!(a.pad_axis(Axis(1), 1, 2, PadMode::Edge).shape(), &[0, 6]);
+}
+
+#[test]
+#[should_panic(expected = "can only be padded with PadMode::Constant")]
+fn pad_axis_empty_axis_panics()
+{
+    let a = Array2::<i32>::zeros((0, 3));
+    a.pad_axis(Axis(0), 1, 0, PadMode::Edge);
+}
+
+#[test]
+fn resize_axis()
+{
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 100 + j * 10 + k) as i32);
+    let cropped = a.resize_axis(Axis(2), 1, 0);
+    assert_eq!(cropped, a.slice(s![.., .., ..1]));
+    let padded = a.resize_axis(Axis(1), 5, -1);
+    assert_eq!(padded.shape(), &[2, 5, 4]);
+    assert_eq!(padded.slice(s![.., ..3, ..]), a);
+    assert!(padded.slice(s![.., 3.., ..]).iter().all(|&x| x == -1));
+    assert_eq!(a.resize_axis(Axis(0), 2, 0), a);
+    assert_eq!(a.resize_axis(Axis(0), 0, 0).shape(), &[0, 3, 4]);
+
+    let empty = Array2::<i32>::zeros((0, 2));
+    assert_eq!(empty.resize_axis(Axis(0), 2, 3), Array2::from_elem((2, 2), 3));
+}
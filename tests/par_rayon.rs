@@ -49,6 +49,21 @@ fn test_regular_iter()
     assert_eq!(s, a.sum());
 }
 
+#[test]
+fn test_par_map_inplace_min_len()
+{
+    let mut a = Array2::<f64>::zeros((M, N));
+    for (i, mut v) in a.axis_iter_mut(Axis(0)).enumerate() {
+        v.fill(i as _);
+    }
+    let b = a.mapv(|x| x + 1.);
+    for &min_len in &[1, 1000, M * N, 2 * M * N] {
+        let mut c = a.clone();
+        c.par_map_inplace_min_len(min_len, |x| *x += 1.);
+        assert_eq!(c, b);
+    }
+}
+
 #[test]
 fn test_regular_iter_collect()
 {
@@ -150,3 +150,41 @@ fn test_zip_assign_into()
 
     assert_abs_diff_eq!(a, &b + &c, epsilon = 1e-6);
 }
+
+#[test]
+fn test_zip_axis_iter_into_output()
+{
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i * N + j) as u64);
+    let mut row_sums = Array1::<u64>::zeros(M);
+    Zip::from(&mut row_sums)
+        .and(a.axis_iter(Axis(0)))
+        .par_for_each(|sum, row| *sum = row.sum());
+    assert_eq!(row_sums, a.sum_axis(Axis(1)));
+
+    let mut col_sums = Array1::<u64>::zeros(N);
+    Zip::from(a.axis_iter(Axis(1))).par_map_assign_into(&mut col_sums, |col| col.sum());
+    assert_eq!(col_sums, a.sum_axis(Axis(0)));
+}
+
+#[test]
+fn test_zip_fold()
+{
+    let a = Array::from_shape_fn((M, N), |(i, j)| (i + j) % 7);
+    let b = Array::from_shape_fn((M, N), |(i, j)| (i * j) % 5);
+
+    let dot = Zip::from(&a)
+        .and(&b)
+        .par_fold(|| 0, |acc, &x, &y| acc + x * y, |acc, other| acc + other);
+
+    let expected = Zip::from(&a).and(&b).fold(0, |acc, &x, &y| acc + x * y);
+    assert_eq!(dot, expected);
+}
+
+#[test]
+fn test_zip_fold_empty()
+{
+    let a = Array2::<usize>::zeros((0, N));
+
+    let count = Zip::from(&a).par_fold(|| 0, |acc, _| acc + 1, |acc, other| acc + other);
+    assert_eq!(count, 0);
+}
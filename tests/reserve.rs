@@ -68,3 +68,103 @@ fn reserve_2d_inverted_with_data()
     assert!(raw_vec.capacity() >= 3 * 100);
     assert_eq!(offset, Some(1));
 }
+
+#[test]
+fn resize_fill_reuses_allocation()
+{
+    let mut a = Array2::<i32>::zeros((4, 8));
+    let ptr = a.as_ptr();
+    a.resize_fill((3, 5), 7);
+    assert_eq!(a, Array2::from_elem((3, 5), 7));
+    assert!(a.is_standard_layout());
+    assert_eq!(a.as_ptr(), ptr);
+
+    a.resize_fill((8, 4).f(), 1);
+    assert_eq!(a, Array2::from_elem((8, 4), 1));
+    assert_eq!(a.strides(), &[1, 8]);
+    assert_eq!(a.as_ptr(), ptr);
+    assert!(into_raw_vec_capacity(a) >= 32);
+}
+
+#[test]
+fn resize_fill_grow()
+{
+    let mut a = array![[1, 2], [3, 4], [5, 6]];
+    a.slice_collapse(s![1.., ..;-1]);
+    a.resize_fill((10, 10), 0);
+    assert_eq!(a, Array2::zeros((10, 10)));
+    assert!(into_raw_vec_capacity(a) >= 100);
+
+    let mut b = Array::from_elem(IxDyn(&[2, 3]), String::from("a"));
+    b.resize_fill(IxDyn(&[2, 2, 2]), String::from("b"));
+    assert_eq!(b, Array::from_elem(IxDyn(&[2, 2, 2]), String::from("b")));
+    b.resize_fill(IxDyn(&[]), String::from("c"));
+    assert_eq!(b, arr0(String::from("c")).into_dyn());
+
+    let mut c = arr0(1);
+    c.resize_fill((), 2);
+    assert_eq!(c, arr0(2));
+}
+
+#[test]
+fn shrink_to_fit_whole_buffer()
+{
+    let mut a = Array2::<i32>::zeros((0, 4));
+    a.reserve(Axis(0), 100).unwrap();
+    a.push_row(ArrayView::from(&[1, 2, 3, 4])).unwrap();
+    a.push_row(ArrayView::from(&[5, 6, 7, 8])).unwrap();
+    a.invert_axis(Axis(0));
+    let strides = a.strides().to_vec();
+    a.shrink_to_fit();
+    assert_eq!(a, array![[5, 6, 7, 8], [1, 2, 3, 4]]);
+    assert_eq!(a.strides(), &strides[..]);
+    assert_eq!(into_raw_vec_capacity(a), 8);
+}
+
+#[test]
+fn shrink_to_fit_compacts()
+{
+    let mut a = Array::from_shape_fn((4, 6).f(), |(i, j)| (i * 10 + j).to_string());
+    a.slice_collapse(s![1..3, ..;2]);
+    let expected = a.to_owned();
+    a.shrink_to_fit();
+    assert_eq!(a, expected);
+    assert!(a.t().is_standard_layout());
+    let (v, offset) = a.into_raw_vec_and_offset();
+    assert_eq!(v.len(), 6);
+    assert_eq!(v.capacity(), 6);
+    assert_eq!(offset, Some(0));
+
+    let mut b = array![1, 2, 3].slice_move(s![1]);
+    b.shrink_to_fit();
+    assert_eq!(b, arr0(2));
+    assert_eq!(into_raw_vec_capacity(b), 1);
+
+    let mut c = array![[1, 2], [3, 4]].into_dyn();
+    c.index_axis_inplace(Axis(0), 1);
+    c.index_axis_inplace(Axis(0), 1);
+    c.shrink_to_fit();
+    assert_eq!(c, arr0(4).into_dyn());
+    assert_eq!(into_raw_vec_capacity(c), 1);
+}
+
+#[test]
+fn make_contiguous()
+{
+    let mut a = array![[1, 2], [3, 4], [5, 6]];
+    let ptr = a.as_ptr();
+    a.make_contiguous();
+    assert_eq!(a.as_ptr(), ptr);
+
+    a.swap_axes(0, 1);
+    a.make_contiguous();
+    assert_eq!(a, array![[1, 3, 5], [2, 4, 6]]);
+    assert_eq!(a.as_slice_mut().unwrap(), &mut [1, 3, 5, 2, 4, 6]);
+
+    let mut b = Array::from_shape_fn((4, 4), |(i, j)| format!("{}{}", i, j));
+    b.slice_collapse(s![..;-2, 1..3]);
+    b.make_contiguous();
+    assert!(b.is_standard_layout());
+    assert_eq!(b, array![["31", "32"], ["11", "12"]].mapv(String::from));
+    assert_eq!(into_raw_vec_capacity(b), 4);
+}
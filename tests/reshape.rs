@@ -2,7 +2,7 @@ use ndarray::prelude::*;
 
 use itertools::enumerate;
 
-use ndarray::Order;
+use ndarray::{ErrorKind, Order};
 
 #[test]
 fn reshape()
@@ -106,6 +106,32 @@ fn to_shape_easy()
     assert_eq!(s, array![[1, 5], [2, 6], [3, 7], [4, 8]]);
 }
 
+#[test]
+fn reshape_view()
+{
+    let a = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    let v = a.reshape_view((2, 2, 3)).unwrap();
+    assert_eq!(v.as_ptr(), a.as_ptr());
+    assert_eq!(v, array![[[0, 1, 2], [3, 4, 5]], [[6, 7, 8], [9, 10, 11]]]);
+    assert!(a.reshape_view((5, 2)).is_none());
+
+    // no copy is made even when the layout is incompatible
+    assert!(a.reshape_view((12, Order::F)).is_none());
+    let t = a.t();
+    let f = t.reshape_view((12, Order::F)).unwrap();
+    assert_eq!(f, Array::from_iter(0..12));
+
+    // non-contiguous axes can still be merged if their strides allow it
+    let s = a.slice(s![..;2, ..]);
+    assert_eq!(s.reshape_view((2, 2, 2)).unwrap(), array![[[0, 1], [2, 3]], [[8, 9], [10, 11]]]);
+    assert!(s.reshape_view(8).is_none());
+
+    let e = Array::<i32, _>::zeros((0, 3));
+    assert_eq!(e.reshape_view((3, 0)).unwrap().shape(), &[3, 0]);
+}
+
 #[test]
 fn to_shape_copy()
 {
@@ -329,3 +355,32 @@ fn into_shape_clone()
         assert_eq!(s, array![[1, 5], [2, 6], [3, 7], [4, 8]]);
     }
 }
+
+#[test]
+fn group_1d()
+{
+    let a = Array::from_iter(0..12);
+    let g = a.view().group_1d(3).unwrap();
+    assert_eq!(g, a.clone().into_shape_with_order((4, 3)).unwrap());
+    assert_eq!(a.view().group_1d(12).unwrap().shape(), &[1, 12]);
+    assert_eq!(a.view().group_1d(1).unwrap().shape(), &[12, 1]);
+
+    // strided and reversed input, which into_shape_with_order can't handle
+    let s = a.slice(s![..;-2]);
+    assert_eq!(s.group_1d(2).unwrap(), array![[11, 9], [7, 5], [3, 1]]);
+
+    // owned arrays keep their data
+    let owned = a.group_1d(4).unwrap();
+    assert_eq!(owned.row(2), array![8, 9, 10, 11]);
+
+    let empty = Array1::<i32>::zeros(0).group_1d(5).unwrap();
+    assert_eq!(empty.shape(), &[0, 5]);
+}
+
+#[test]
+fn group_1d_error()
+{
+    let a = Array::from_iter(0..10);
+    assert_eq!(a.view().group_1d(3).unwrap_err().kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(a.view().group_1d(0).unwrap_err().kind(), ErrorKind::IncompatibleShape);
+}
@@ -349,3 +349,76 @@ fn test_windows_with_stride_on_inverted_axis()
             arr2(&[[6, 5], [2, 1]]),
         ]);
 }
+
+#[test]
+fn test_rolling_axis()
+{
+    let a = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+
+    let sums = a.rolling_axis(Axis(1), 2, |w| w.sum());
+    assert_eq!(sums, arr2(&[[1, 3, 5], [9, 11, 13], [17, 19, 21]]));
+
+    let sums = a.rolling_axis(Axis(0), 3, |w| w.sum());
+    assert_eq!(sums, arr2(&[[12, 15, 18, 21]]));
+
+    let sums = a.t().rolling_axis(Axis(0), 4, |w| w.sum());
+    assert_eq!(sums, arr2(&[[6, 22, 38]]));
+}
+
+#[test]
+fn test_rolling_axis_oversized()
+{
+    let a = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    let sums = a.rolling_axis(Axis(1), 5, |w| w.sum());
+    assert_eq!(sums.shape(), &[3, 0]);
+}
+
+#[test]
+#[should_panic]
+fn test_rolling_axis_zero_size()
+{
+    let a = Array::from_iter(0..12)
+        .into_shape_with_order((3, 4))
+        .unwrap();
+    a.rolling_axis(Axis(1), 0, |w| w.sum());
+}
+
+#[test]
+fn test_axis_windows_owned()
+{
+    let a = Array::from_iter(0..40)
+        .into_shape_with_order((2, 10, 2))
+        .unwrap();
+
+    for &(size, stride) in &[(1, 1), (3, 1), (3, 2), (4, 3), (10, 1), (2, 5)] {
+        let windows: Vec<Array3<i32>> = a.axis_windows_owned(Axis(1), size, stride).collect();
+        assert_eq!(windows.len(), (10 - size) / stride + 1);
+        for (k, w) in windows.iter().enumerate() {
+            assert_eq!(w, &a.slice(s![.., k * stride..k * stride + size, ..]));
+        }
+    }
+    assert!(a.axis_windows_owned(Axis(1), 3, 1).eq(a.axis_windows(Axis(1), 3)));
+
+    let t = a.t();
+    let mut iter = t.axis_windows_owned(Axis(1), 4, 3);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back().unwrap(), t.slice(s![.., 6..10, ..]));
+    assert_eq!(iter.next().unwrap(), t.slice(s![.., 0..4, ..]));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next().unwrap(), t.slice(s![.., 3..7, ..]));
+    assert!(iter.next().is_none() && iter.next_back().is_none());
+
+    assert_eq!(a.axis_windows_owned(Axis(0), 3, 1).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "stride must be greater than zero")]
+fn test_axis_windows_owned_zero_stride()
+{
+    let a = Array2::<i32>::zeros((3, 4));
+    a.axis_windows_owned(Axis(1), 2, 0);
+}